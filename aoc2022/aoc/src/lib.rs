@@ -0,0 +1,81 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::quote;
+use syn::{parse_macro_input, Error, Fields, Item, ItemFn, ItemStruct, LitInt};
+
+/// Registers a day with the runner, either on the struct implementing
+/// `runner::Day` or on free `part1`/`part2` functions taking the input.
+///
+/// ```ignore
+/// #[aoc::day(8)]
+/// pub struct Day8;
+///
+/// #[aoc::day(9)]
+/// fn part1(input: &str) -> anyhow::Result<Answer> { ... }
+/// ```
+#[proc_macro_attribute]
+pub fn day(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let day = parse_macro_input!(attr as LitInt);
+    let item = parse_macro_input!(item as Item);
+    let expanded = match day.base10_parse::<usize>() {
+        Ok(0) => Err(Error::new(day.span(), "days start at 1")),
+        Ok(_) => match &item {
+            Item::Struct(item) => Ok(register_struct(&day, item)),
+            Item::Fn(item) => register_part(&day, item),
+            _ => Err(Error::new(
+                Span::call_site(),
+                "expected a struct or a `part1`/`part2` function",
+            )),
+        },
+        Err(e) => Err(e),
+    };
+    match expanded {
+        Ok(registration) => quote!(#item #registration).into(),
+        Err(e) => e.to_compile_error().into(),
+    }
+}
+
+fn register_struct(day: &LitInt, item: &ItemStruct) -> proc_macro2::TokenStream {
+    let name = &item.ident;
+    let value = match item.fields {
+        Fields::Unit => quote!(#name),
+        _ => quote!(<#name as ::std::default::Default>::default()),
+    };
+    quote! {
+        const _: () = {
+            fn make() -> ::std::boxed::Box<dyn ::runner::Day> {
+                ::std::boxed::Box::new(#value)
+            }
+            ::runner::inventory::submit! {
+                ::runner::Registration {
+                    module: ::std::module_path!(),
+                    day: #day,
+                    solution: ::runner::Registered::Day(make),
+                }
+            }
+        };
+    }
+}
+
+fn register_part(day: &LitInt, item: &ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let name = &item.sig.ident;
+    let part: u8 = match name.to_string().as_str() {
+        "part1" => 1,
+        "part2" => 2,
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "expected a function named `part1` or `part2`",
+            ))
+        }
+    };
+    Ok(quote! {
+        ::runner::inventory::submit! {
+            ::runner::Registration {
+                module: ::std::module_path!(),
+                day: #day,
+                solution: ::runner::Registered::Part(#part, #name),
+            }
+        }
+    })
+}
@@ -1,6 +1,7 @@
-use runner::Day;
+use runner::{Answer, Day, Example};
 use std::{collections::VecDeque, io::BufRead};
 
+#[aoc::day(1)]
 pub struct Day1;
 
 struct Elf {
@@ -62,46 +63,42 @@ fn calculate_all_elves<R: BufRead>(read: R) -> Elves {
 }
 
 impl Day for Day1 {
-    fn part1(&self, input: &str) -> anyhow::Result<String> {
+    fn part1(&self, input: &str) -> anyhow::Result<Answer> {
         let elves = calculate_all_elves(input.as_bytes());
-        Ok(elves.first().total_calories.to_string())
+        Ok(elves.first().total_calories.into())
     }
 
-    fn part2(&self, input: &str) -> anyhow::Result<String> {
+    fn part2(&self, input: &str) -> anyhow::Result<Answer> {
         let mut elves = calculate_all_elves(input.as_bytes());
         Ok(elves.0.make_contiguous()[..3]
             .iter()
             .map(|e| e.total_calories)
             .sum::<usize>()
-            .to_string())
+            .into())
     }
 
     fn day(&self) -> usize {
         1
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+const EXAMPLES: &[Example] = &[Example::new(EXAMPLE, "24000", "45000")];
 
-    #[test]
-    fn example_input() {
-        let input = r"1000
-            2000
-            3000
+const EXAMPLE: &str = r"1000
+                        2000
+                        3000
 
-            4000
+                        4000
 
-            5000
-            6000
+                        5000
+                        6000
 
-            7000
-            8000
-            9000
+                        7000
+                        8000
+                        9000
 
-            10000";
-        let elf = Day1.part1(input).unwrap();
-        assert_eq!(elf, "24000");
-    }
-}
+                        10000";
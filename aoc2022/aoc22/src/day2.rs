@@ -1,11 +1,12 @@
 use std::ops::Add;
 
-use runner::Day;
+use runner::{Answer, Day, Example};
 
+#[aoc::day(2)]
 pub struct Day2;
 
 impl Day for Day2 {
-    fn part1(&self, input: &str) -> anyhow::Result<String> {
+    fn part1(&self, input: &str) -> anyhow::Result<Answer> {
         input
             .lines()
             .try_fold(0, |score, line| -> anyhow::Result<usize> {
@@ -17,10 +18,10 @@ impl Day for Day2 {
 
                 Ok(score + round)
             })
-            .map(|score| score.to_string())
+            .map(Answer::from)
     }
 
-    fn part2(&self, input: &str) -> anyhow::Result<String> {
+    fn part2(&self, input: &str) -> anyhow::Result<Answer> {
         input
             .lines()
             .try_fold(0, |score, line| -> anyhow::Result<usize> {
@@ -32,12 +33,16 @@ impl Day for Day2 {
 
                 Ok(score + round)
             })
-            .map(|score| score.to_string())
+            .map(Answer::from)
     }
 
     fn day(&self) -> usize {
         2
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -117,28 +122,8 @@ impl Add<Game> for Choices {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use runner::Day;
-
-    use super::Day2;
-
-    const INPUT: &str = r"A Y
-                          B X
-                          C Z";
-
-    #[test]
-    fn part1() -> anyhow::Result<()> {
-        let response = Day2.part1(INPUT)?;
+const EXAMPLES: &[Example] = &[Example::new(EXAMPLE, "15", "12")];
 
-        assert_eq!(response, "15");
-        Ok(())
-    }
-    #[test]
-    fn part2() -> anyhow::Result<()> {
-        let response = Day2.part2(INPUT)?;
-
-        assert_eq!(response, "12");
-        Ok(())
-    }
-}
+const EXAMPLE: &str = r"A Y
+                        B X
+                        C Z";
@@ -1,12 +1,13 @@
 use std::collections::{HashMap, HashSet};
 
 use itertools::Itertools;
-use runner::Day;
+use runner::{Answer, Day, Example};
 
+#[aoc::day(3)]
 pub struct Day3;
 
 impl Day for Day3 {
-    fn part1(&self, input: &str) -> anyhow::Result<String> {
+    fn part1(&self, input: &str) -> anyhow::Result<Answer> {
         let priorities = priorities();
         Ok(input
             .lines()
@@ -24,10 +25,10 @@ impl Day for Day3 {
                     .unwrap()
             })
             .sum::<usize>()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> anyhow::Result<String> {
+    fn part2(&self, input: &str) -> anyhow::Result<Answer> {
         let elf_groups = input
             .lines()
             .map(|line| {
@@ -45,40 +46,27 @@ impl Day for Day3 {
             })
             .filter_map(|badge| priorities().get(&badge).copied())
             .sum::<usize>()
-            .to_string())
+            .into())
     }
 
     fn day(&self) -> usize {
         3
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 fn priorities() -> HashMap<char, usize> {
     ('a'..='z').chain('A'..='Z').zip(1..=52).collect()
 }
 
-#[cfg(test)]
-mod test {
-    use runner::Day;
-
-    use super::Day3;
-
-    const INPUT: &str = r"vJrwpWtwJgWrhcsFMMfFFhFp
-                          jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
-                          PmmdzqPrVvPwwTWBwg
-                          wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
-                          ttgJtRGJQctTZtZT
-                          CrZsJsPPZsGzwwsLwLmpwMDw";
-    #[test]
-    fn part1() {
-        let res = Day3.part1(INPUT).unwrap();
+const EXAMPLES: &[Example] = &[Example::new(EXAMPLE, "157", "70")];
 
-        assert_eq!(res, "157");
-    }
-
-    #[test]
-    fn part2() {
-        let res = Day3.part2(INPUT).unwrap();
-        assert_eq!(res, "70");
-    }
-}
+const EXAMPLE: &str = r"vJrwpWtwJgWrhcsFMMfFFhFp
+                        jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+                        PmmdzqPrVvPwwTWBwg
+                        wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+                        ttgJtRGJQctTZtZT
+                        CrZsJsPPZsGzwwsLwLmpwMDw";
@@ -1,12 +1,13 @@
 use std::collections::HashSet;
 
 use itertools::Itertools;
-use runner::Day;
+use runner::{Answer, Day, Example};
 
+#[aoc::day(4)]
 pub struct Day4;
 
 impl Day for Day4 {
-    fn part1(&self, input: &str) -> anyhow::Result<String> {
+    fn part1(&self, input: &str) -> anyhow::Result<Answer> {
         Ok(input
             .lines()
             .filter_map(|line| line.trim().split_once(','))
@@ -18,10 +19,10 @@ impl Day for Day4 {
             })
             .filter(|(first, second)| first.completly_intersects(second))
             .count()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> anyhow::Result<String> {
+    fn part2(&self, input: &str) -> anyhow::Result<Answer> {
         Ok(input
             .lines()
             .filter_map(|line| {
@@ -32,12 +33,16 @@ impl Day for Day4 {
             })
             .filter(|(first, second)| first.0.intersection(&second.0).count() != 0)
             .count()
-            .to_string())
+            .into())
     }
 
     fn day(&self) -> usize {
         4
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 struct Sections(HashSet<usize>);
@@ -66,30 +71,11 @@ impl TryFrom<&str> for Sections {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use runner::Day;
-
-    use super::Day4;
-
-    const INPUT: &str = r"2-4,6-8
-                          2-3,4-5
-                          5-7,7-9
-                          2-8,3-7
-                          6-6,4-6
-                          2-6,4-8";
+const EXAMPLES: &[Example] = &[Example::new(EXAMPLE, "2", "4")];
 
-    #[test]
-    fn part1() -> anyhow::Result<()> {
-        let res = Day4.part1(INPUT)?;
-        assert_eq!(res, "2");
-        Ok(())
-    }
-
-    #[test]
-    fn part2() -> anyhow::Result<()> {
-        let res = Day4.part2(INPUT)?;
-        assert_eq!(res, "4");
-        Ok(())
-    }
-}
+const EXAMPLE: &str = r"2-4,6-8
+                        2-3,4-5
+                        5-7,7-9
+                        2-8,3-7
+                        6-6,4-6
+                        2-6,4-8";
@@ -9,13 +9,20 @@ use nom::{
     IResult, Parser,
 };
 use nom_supreme::{final_parser::final_parser, ParserExt};
-use runner::Day;
+use runner::{Answer, Example, Solution};
 
+#[aoc::day(5)]
 pub struct Day5;
 
-impl Day for Day5 {
-    fn part1(&self, input: &str) -> anyhow::Result<String> {
-        let (mut stacks, moves) = parse(input);
+impl Solution for Day5 {
+    type Input = (Vec<Stack>, Vec<Move>);
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(parse(input))
+    }
+
+    fn part1(&self, (stacks, moves): &Self::Input) -> anyhow::Result<Answer> {
+        let mut stacks = stacks.clone();
 
         for mv in moves {
             for _i in 0..mv.num {
@@ -27,11 +34,12 @@ impl Day for Day5 {
         Ok(stacks
             .into_iter()
             .filter_map(|mut s| s.contents.pop_front())
-            .join(""))
+            .join("")
+            .into())
     }
 
-    fn part2(&self, input: &str) -> anyhow::Result<String> {
-        let (mut stacks, moves) = parse(input);
+    fn part2(&self, (stacks, moves): &Self::Input) -> anyhow::Result<Answer> {
+        let mut stacks = stacks.clone();
 
         for mv in moves {
             let crts = stacks[mv.from()].take_n(mv.num as usize);
@@ -40,12 +48,17 @@ impl Day for Day5 {
         Ok(stacks
             .into_iter()
             .filter_map(|mut s| s.contents.pop_front())
-            .join(""))
+            .join("")
+            .into())
     }
 
     fn day(&self) -> usize {
         5
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 fn parse(input: &str) -> (Vec<Stack>, Vec<Move>) {
@@ -91,7 +104,8 @@ fn no_crate(input: &str) -> IResult<&str, Option<char>> {
         .parse(input)
 }
 
-struct Move {
+#[derive(Debug, Clone)]
+pub struct Move {
     num: u8,
     src: usize,
     dst: usize,
@@ -125,8 +139,8 @@ fn dst(input: &str) -> IResult<&str, usize> {
         .parse(input)
 }
 
-#[derive(Debug)]
-struct Stack {
+#[derive(Debug, Clone)]
+pub struct Stack {
     idx: usize,
     contents: VecDeque<char>,
 }
@@ -169,11 +183,9 @@ impl Stack {
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const INPUT: &str = "    [D]    \n\
+macro_rules! example {
+    () => {
+        "    [D]    \n\
 [N] [C]    \n\
 [Z] [M] [P]\n\
  1   2   3\n\
@@ -181,20 +193,12 @@ mod tests {
 move 1 from 2 to 1\n\
 move 3 from 1 to 3\n\
 move 2 from 2 to 1\n\
-move 1 from 1 to 2";
-
-    #[test]
-    fn part1() -> anyhow::Result<()> {
-        println!("{}", INPUT);
-        let res = Day5.part1(INPUT)?;
-        assert_eq!(res, "CMZ");
-        Ok(())
-    }
-
-    #[test]
-    fn part2() -> anyhow::Result<()> {
-        let res = Day5.part2(INPUT)?;
-        assert_eq!(res, "MCD");
-        Ok(())
-    }
+move 1 from 1 to 2"
+    };
 }
+
+const EXAMPLES: &[Example] = &[
+    Example::new(example!(), "CMZ", "MCD"),
+    // saved by an editor adding a byte order mark
+    Example::new(concat!("\u{feff}", example!()), "CMZ", "MCD"),
+];
@@ -1,12 +1,13 @@
 use std::collections::HashSet;
 
 use itertools::Itertools;
-use runner::Day;
+use runner::{Answer, Day, Example};
 
+#[aoc::day(6)]
 pub struct Day6;
 
 impl Day for Day6 {
-    fn part1(&self, input: &str) -> anyhow::Result<String> {
+    fn part1(&self, input: &str) -> anyhow::Result<Answer> {
         Ok(input
             .trim()
             .char_indices()
@@ -17,10 +18,10 @@ impl Day for Day6 {
                 (set.len() == 4).then_some(c.last().unwrap().0 + 1)
             })
             .unwrap()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> anyhow::Result<String> {
+    fn part2(&self, input: &str) -> anyhow::Result<Answer> {
         Ok(input
             .trim()
             .char_indices()
@@ -31,42 +32,22 @@ impl Day for Day6 {
                 (set.len() == 14).then_some(c.last().unwrap().0 + 1)
             })
             .unwrap()
-            .to_string())
+            .into())
     }
 
     fn day(&self) -> usize {
         6
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use pretty_assertions::assert_eq;
-
-    const INPUTS: [(&str, &str, &str); 5] = [
-        ("mjqjpqmgbljsphdztnvjfqwrcgsmlb", "7", "19"),
-        ("bvwbjplbgvbhsrlpgdmjqwftvncz", "5", "23"),
-        ("nppdvjthqldpwncqszvftbrmjlhg", "6", "23"),
-        ("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", "10", "29"),
-        ("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", "11", "26"),
-    ];
 
-    #[test]
-    fn part1() -> anyhow::Result<()> {
-        for (input, expected, _) in INPUTS {
-            let res = Day6.part1(input)?;
-            assert_eq!(res, expected);
-        }
-        Ok(())
-    }
-
-    #[test]
-    fn part2() -> anyhow::Result<()> {
-        for (input, _, expected) in INPUTS {
-            let res = Day6.part2(input)?;
-            assert_eq!(res, expected);
-        }
-        Ok(())
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
     }
 }
+
+const EXAMPLES: &[Example] = &[
+    Example::new("mjqjpqmgbljsphdztnvjfqwrcgsmlb", "7", "19"),
+    Example::new("bvwbjplbgvbhsrlpgdmjqwftvncz", "5", "23"),
+    Example::new("nppdvjthqldpwncqszvftbrmjlhg", "6", "23"),
+    Example::new("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg", "10", "29"),
+    Example::new("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw", "11", "26"),
+];
@@ -12,14 +12,19 @@ use nom::{
     IResult, Parser,
 };
 use nom_supreme::{final_parser::final_parser, ParserExt};
-use runner::Day;
+use runner::{tracing::debug, Answer, Example, Solution};
 
+#[aoc::day(7)]
 pub struct Day7;
 
-impl Day for Day7 {
-    fn part1(&self, input: &str) -> anyhow::Result<String> {
-        let root = Node::from_input(input);
+impl Solution for Day7 {
+    type Input = Node;
 
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(Node::from_input(input))
+    }
+
+    fn part1(&self, root: &Self::Input) -> anyhow::Result<Answer> {
         let mut dirs = Vec::new();
 
         root.find_dirs("/".into(), &mut dirs);
@@ -29,11 +34,10 @@ impl Day for Day7 {
             .filter(|(_, sz)| *sz <= 100000)
             .map(|(_, sz)| sz)
             .sum::<u64>()
-            .to_string())
+            .into())
     }
 
-    fn part2(&self, input: &str) -> anyhow::Result<String> {
-        let root = Node::from_input(input);
+    fn part2(&self, root: &Self::Input) -> anyhow::Result<Answer> {
         let max_disk = 70_000_000;
         let needed_free_space = 30_000_000;
 
@@ -46,17 +50,21 @@ impl Day for Day7 {
             .filter(|(_, sz)| *sz > space_to_free)
             .min_by(|(_, sz1), (_, sz2)| sz1.cmp(sz2))
             .ok_or_else(|| anyhow!("thing is empty"))?;
-        println!("{} is smallest dir to free at least {}", dir, space_to_free);
-        Ok(sz.to_string())
+        debug!(dir, size = sz, space_to_free, "smallest dir to free");
+        Ok(sz.into())
     }
 
     fn day(&self) -> usize {
         7
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 #[derive(Debug)]
-struct Node {
+pub struct Node {
     size: u64,
     children: HashMap<String, Node>,
 }
@@ -107,7 +115,7 @@ impl Node {
                         Element::Dir(name) => {
                             current.children.insert(name, Node::new());
                         }
-                        Element::File(size, _) => current.size += size,
+                        Element::File(size) => current.size += size,
                     }
                 }
             }
@@ -164,14 +172,13 @@ fn directory<'a>() -> impl Parser<&'a str, Directory, Error<&'a str>> {
 
 enum Element {
     Dir(String),
-    File(u64, String),
+    File(u64),
 }
 
 fn list<'a>() -> impl Parser<&'a str, Element, Error<&'a str>> {
     preceded(tag("dir "), rest)
         .map(|n: &str| Element::Dir(n.to_owned()))
-        .or(pair(u64, multispace1.precedes(rest))
-            .map(|(size, name): (_, &str)| Element::File(size, name.to_owned())))
+        .or(pair(u64, multispace1.precedes(rest)).map(|(size, _): (_, &str)| Element::File(size)))
 }
 
 fn line(input: &str) -> Result<Either<Command, Element>, Error<&str>> {
@@ -183,46 +190,28 @@ fn line(input: &str) -> Result<Either<Command, Element>, Error<&str>> {
     )(input)
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use pretty_assertions::assert_eq;
-
-    const INPUT: &str = r"$ cd /
-                          $ ls
-                          dir a
-                          14848514 b.txt
-                          8504156 c.dat
-                          dir d
-                          $ cd a
-                          $ ls
-                          dir e
-                          29116 f
-                          2557 g
-                          62596 h.lst
-                          $ cd e
-                          $ ls
-                          584 i
-                          $ cd ..
-                          $ cd ..
-                          $ cd d
-                          $ ls
-                          4060174 j
-                          8033020 d.log
-                          5626152 d.ext
-                          7214296 k";
-
-    #[test]
-    fn part1() -> anyhow::Result<()> {
-        let res = Day7.part1(INPUT)?;
-        assert_eq!(res, "95437");
-        Ok(())
-    }
-
-    #[test]
-    fn part2() -> anyhow::Result<()> {
-        let res = Day7.part2(INPUT)?;
-        assert_eq!(res, "24933642");
-        Ok(())
-    }
-}
+const EXAMPLES: &[Example] = &[Example::new(EXAMPLE, "95437", "24933642")];
+
+const EXAMPLE: &str = r"$ cd /
+                        $ ls
+                        dir a
+                        14848514 b.txt
+                        8504156 c.dat
+                        dir d
+                        $ cd a
+                        $ ls
+                        dir e
+                        29116 f
+                        2557 g
+                        62596 h.lst
+                        $ cd e
+                        $ ls
+                        584 i
+                        $ cd ..
+                        $ cd ..
+                        $ cd d
+                        $ ls
+                        4060174 j
+                        8033020 d.log
+                        5626152 d.ext
+                        7214296 k";
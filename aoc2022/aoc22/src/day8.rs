@@ -1,11 +1,14 @@
 use itertools::Itertools;
-use runner::Day;
+use runner::{Answer, Example, Solution};
 
+#[aoc::day(8)]
 pub struct Day8;
 
-impl Day for Day8 {
-    fn part1(&self, input: &str) -> anyhow::Result<String> {
-        let map: Vec<Vec<u8>> = input
+impl Solution for Day8 {
+    type Input = Vec<Vec<u8>>;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+        Ok(input
             .lines()
             .map(str::trim)
             .map(|line| {
@@ -14,7 +17,10 @@ impl Day for Day8 {
                     .map(|n| n as u8)
                     .collect()
             })
-            .collect();
+            .collect())
+    }
+
+    fn part1(&self, map: &Self::Input) -> anyhow::Result<Answer> {
         let rows = map.len();
         let cols = map[0].len();
         let mut visible = rows * 2 + cols * 2 - 4;
@@ -37,20 +43,10 @@ impl Day for Day8 {
                 }
             }
         }
-        Ok(visible.to_string())
+        Ok(visible.into())
     }
 
-    fn part2(&self, input: &str) -> anyhow::Result<String> {
-        let map: Vec<Vec<u8>> = input
-            .lines()
-            .map(str::trim)
-            .map(|line| {
-                line.chars()
-                    .filter_map(|n| n.to_digit(10))
-                    .map(|n| n as u8)
-                    .collect()
-            })
-            .collect();
+    fn part2(&self, map: &Self::Input) -> anyhow::Result<Answer> {
         let rows = map.len();
         let cols = map[0].len();
 
@@ -71,12 +67,16 @@ impl Day for Day8 {
                 scores.push(score);
             }
         }
-        Ok(scores.iter().max().unwrap().to_string())
+        Ok((*scores.iter().max().unwrap()).into())
     }
 
     fn day(&self) -> usize {
         8
     }
+
+    fn examples(&self) -> &'static [Example] {
+        EXAMPLES
+    }
 }
 
 fn count_visible<'a, I: IntoIterator<Item = &'a u8>>(
@@ -93,28 +93,10 @@ fn count_visible<'a, I: IntoIterator<Item = &'a u8>>(
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use pretty_assertions::assert_eq;
-
-    const INPUT: &str = r"30373
-                          25512
-                          65332
-                          33549
-                          35390";
+const EXAMPLES: &[Example] = &[Example::new(EXAMPLE, "21", "8")];
 
-    #[test]
-    fn part1() -> anyhow::Result<()> {
-        let res = Day8.part1(INPUT)?;
-        assert_eq!(res, "21");
-        Ok(())
-    }
-
-    #[test]
-    fn part2() -> anyhow::Result<()> {
-        let res = Day8.part2(INPUT)?;
-        assert_eq!(res, "8");
-        Ok(())
-    }
-}
+const EXAMPLE: &str = r"30373
+                        25512
+                        65332
+                        33549
+                        35390";
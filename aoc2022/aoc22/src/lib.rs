@@ -1,4 +1,4 @@
-use runner::Runner;
+use runner::{Day, Output, Runner, Scaffold};
 
 mod day1;
 mod day2;
@@ -9,17 +9,16 @@ mod day6;
 mod day7;
 mod day8;
 
-pub fn run() -> anyhow::Result<String> {
-    let runner = Runner::new(vec![
-        Box::new(day1::Day1),
-        Box::new(day2::Day2),
-        Box::new(day3::Day3),
-        Box::new(day4::Day4),
-        Box::new(day5::Day5),
-        Box::new(day6::Day6),
-        Box::new(day7::Day7),
-        Box::new(day8::Day8),
-    ]);
+pub const YEAR: u16 = 2022;
 
-    runner.run()
+/// Every day marked with `#[aoc::day(N)]`.
+pub fn days() -> anyhow::Result<Vec<Box<dyn Day>>> {
+    runner::registered(module_path!())
+}
+
+pub fn run() -> anyhow::Result<Output> {
+    Runner::new()
+        .with_year(YEAR, days()?)?
+        .with_scaffold(YEAR, Scaffold::new(env!("CARGO_MANIFEST_DIR")))
+        .run()
 }
@@ -1,6 +1,8 @@
-fn main() -> anyhow::Result<()> {
-    let result = aoc22::run()?;
+use std::process::ExitCode;
 
-    println!("{}", result);
-    Ok(())
+fn main() -> anyhow::Result<ExitCode> {
+    let output = aoc22::run()?;
+
+    println!("{}", output.text);
+    Ok(output.exit_code())
 }
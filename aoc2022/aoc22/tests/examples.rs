@@ -0,0 +1,12 @@
+use aoc22::{days, YEAR};
+use runner::Runner;
+
+/// Runs the examples every day declares through `Day::examples`.
+#[test]
+fn examples() -> anyhow::Result<()> {
+    let examples = Runner::new()
+        .with_year(YEAR, days()?)?
+        .examples(YEAR, None)?;
+    assert!(examples.success(), "\n{}", examples);
+    Ok(())
+}
@@ -0,0 +1,41 @@
+use runner::{registered, Answer, Registry};
+
+#[aoc::day(3)]
+fn part1(input: &str) -> anyhow::Result<Answer> {
+    Ok(input.len().into())
+}
+
+mod day5 {
+    use runner::{Answer, Day};
+
+    #[aoc::day(5)]
+    pub struct Day5;
+
+    impl Day for Day5 {
+        fn part2(&self, input: &str) -> anyhow::Result<Answer> {
+            Ok(input.lines().count().into())
+        }
+
+        fn day(&self) -> usize {
+            5
+        }
+    }
+}
+
+#[test]
+fn registers_structs_and_functions() -> anyhow::Result<()> {
+    let registry = Registry::new(registered(module_path!())?)?;
+    assert_eq!(registry.get(3)?.part1("abcd")?, Answer::Int(4));
+    assert!(registry.get(3)?.part2("abcd").is_err());
+    assert_eq!(registry.get(5)?.part2("a\nb\nc")?, Answer::Int(3));
+    Ok(())
+}
+
+#[test]
+fn registers_every_day() -> anyhow::Result<()> {
+    let registry = Registry::new(aoc22::days()?)?;
+    for day in 1..=8 {
+        assert_eq!(registry.get(day)?.day(), day);
+    }
+    Ok(())
+}
@@ -0,0 +1,17 @@
+use std::{env, process::Command};
+
+/// Records the version of the compiler building the runner, benchmarks report
+/// it rather than whichever toolchain is current when they run.
+fn main() {
+    let rustc = env::var("RUSTC").unwrap_or_else(|_| "rustc".to_owned());
+    let version = Command::new(rustc)
+        .arg("--version")
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .map(|out| out.trim().to_owned())
+        .unwrap_or_else(|| "unknown".to_owned());
+    println!("cargo:rustc-env=AOC_RUSTC_VERSION={}", version);
+    println!("cargo:rerun-if-changed=build.rs");
+}
@@ -0,0 +1,137 @@
+use std::fmt::Display;
+
+use serde::{Serialize, Serializer};
+
+/// The answer to a part.
+#[derive(Debug, Clone)]
+pub enum Answer {
+    Int(i128),
+    Text(String),
+    /// Multi-line output, e.g. letters drawn on a screen.
+    Art(Vec<String>),
+}
+
+impl Answer {
+    /// Lines with trailing whitespace and trailing empty lines removed, which
+    /// is how answers are compared.
+    fn normalized_lines(&self) -> Vec<String> {
+        let rendered = self.to_string();
+        let mut lines = rendered
+            .lines()
+            .map(|l| l.trim_end().to_owned())
+            .collect::<Vec<_>>();
+        while lines.last().is_some_and(String::is_empty) {
+            lines.pop();
+        }
+        lines
+    }
+
+    pub fn is_multiline(&self) -> bool {
+        matches!(self, Answer::Art(lines) if lines.len() > 1)
+    }
+
+    /// A single line rendering, for places like tables.
+    pub fn summary(&self) -> String {
+        match self {
+            Answer::Art(lines) if lines.len() > 1 => format!("[art, {} lines]", lines.len()),
+            _ => self.to_string(),
+        }
+    }
+}
+
+/// Answers are compared as rendered, line by line and ignoring trailing
+/// whitespace, so integers equal their decimal representation only.
+impl PartialEq for Answer {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Answer::Int(a), Answer::Int(b)) => a == b,
+            _ => self.normalized_lines() == other.normalized_lines(),
+        }
+    }
+}
+
+impl PartialEq<&str> for Answer {
+    fn eq(&self, other: &&str) -> bool {
+        let other = Answer::from(*other);
+        *self == other
+    }
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Answer::Int(i) => write!(f, "{}", i),
+            Answer::Text(s) => write!(f, "{}", s),
+            Answer::Art(lines) => write!(f, "{}", lines.join("\n")),
+        }
+    }
+}
+
+/// Integers are numbers, everything else a string.
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Int(i) => serializer.serialize_i128(*i),
+            _ => serializer.serialize_str(&self.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        if s.trim_end().contains('\n') {
+            Answer::Art(s.lines().map(str::to_owned).collect())
+        } else {
+            Answer::Text(s)
+        }
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(s: &str) -> Self {
+        s.to_owned().into()
+    }
+}
+
+macro_rules! from_int {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(i: $t) -> Self {
+                    Answer::Int(i as i128)
+                }
+            }
+        )*
+    };
+}
+
+from_int!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn comparisons() {
+        assert_eq!(Answer::from(24000u64), "24000");
+        assert_eq!(Answer::from(24000u64), Answer::from("24000 \n"));
+        assert_ne!(Answer::from(24000u64), "24001");
+        for other in [" 24000", "+24000", "024000"] {
+            assert_ne!(Answer::from(24000u64), other);
+            assert_ne!(Answer::from("24000"), other);
+        }
+        assert_eq!(Answer::from("CMZ"), "CMZ");
+        assert_eq!(Answer::from("#..#\n#..#\n"), "#..#  \n#..#");
+        assert_ne!(Answer::from("#..#\n#..#"), "#..#\n#.##");
+    }
+
+    #[test]
+    fn rendering() {
+        let art = Answer::from("#..#\n####");
+        assert!(art.is_multiline());
+        assert_eq!(art.to_string(), "#..#\n####");
+        assert_eq!(art.summary(), "[art, 2 lines]");
+        assert_eq!(serde_json::to_string(&Answer::from(21u8)).unwrap(), "21");
+        assert_eq!(serde_json::to_string(&art).unwrap(), r##""#..#\n####""##);
+    }
+}
@@ -0,0 +1,243 @@
+use std::{fmt::Display, fs, path::Path, process::Command, time::Duration};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    bench::{BenchReport, Stats},
+    timing::Human,
+};
+
+/// Benchmark results saved to disk so later runs can be compared against them.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub environment: Environment,
+    pub results: Vec<Record>,
+}
+
+/// Where the baseline was recorded, to explain differences unrelated to the
+/// solutions themselves.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Environment {
+    pub rustc: String,
+    pub cpu: String,
+    pub git_revision: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub iterations: usize,
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl From<&BenchReport> for Record {
+    fn from(report: &BenchReport) -> Self {
+        let Stats {
+            min,
+            median,
+            mean,
+            stddev,
+        } = report.stats;
+        Self {
+            year: report.year,
+            day: report.day,
+            part: report.part,
+            iterations: report.iterations,
+            min_ns: min.as_nanos() as u64,
+            median_ns: median.as_nanos() as u64,
+            mean_ns: mean.as_nanos() as u64,
+            stddev_ns: stddev.as_nanos() as u64,
+        }
+    }
+}
+
+impl Baseline {
+    /// Loads a baseline, returning an empty one if the file does not exist.
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let contents = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&contents)?)
+    }
+
+    /// Records `reports`, replacing previous results for the same parts, and
+    /// writes the baseline to `path`.
+    pub fn save(mut self, path: &Path, reports: &[BenchReport]) -> anyhow::Result<()> {
+        self.environment = Environment::current();
+        for report in reports {
+            self.results
+                .retain(|r| (r.year, r.day, r.part) != (report.year, report.day, report.part));
+            self.results.push(report.into());
+        }
+        self.results.sort_by_key(|r| (r.year, r.day, r.part));
+        fs::write(path, serde_json::to_string_pretty(&self)?)?;
+        Ok(())
+    }
+
+    pub fn get(&self, year: u16, day: usize, part: u8) -> Option<&Record> {
+        self.results
+            .iter()
+            .find(|r| (r.year, r.day, r.part) == (year, day, part))
+    }
+
+    /// Compares the median of each report against the baseline, flagging
+    /// parts slower by more than `threshold` percent.
+    pub fn compare(&self, reports: &[BenchReport], threshold: f64) -> Vec<Comparison> {
+        reports
+            .iter()
+            .map(|report| {
+                let before = self
+                    .get(report.year, report.day, report.part)
+                    .map(|r| Duration::from_nanos(r.median_ns));
+                Comparison {
+                    year: report.year,
+                    day: report.day,
+                    part: report.part,
+                    before,
+                    after: report.stats.median,
+                    threshold,
+                }
+            })
+            .collect()
+    }
+}
+
+impl Environment {
+    pub fn current() -> Self {
+        Self {
+            rustc: env!("AOC_RUSTC_VERSION").to_owned(),
+            cpu: cpu_model(),
+            git_revision: command_output("git", &["rev-parse", "--short", "HEAD"]),
+        }
+    }
+}
+
+fn command_output(program: &str, args: &[&str]) -> String {
+    Command::new(program)
+        .args(args)
+        .output()
+        .ok()
+        .filter(|out| out.status.success())
+        .and_then(|out| String::from_utf8(out.stdout).ok())
+        .map(|out| out.trim().to_owned())
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+fn cpu_model() -> String {
+    fs::read_to_string("/proc/cpuinfo")
+        .ok()
+        .and_then(|info| {
+            info.lines()
+                .find(|l| l.starts_with("model name"))
+                .and_then(|l| l.split_once(':'))
+                .map(|(_, model)| model.trim().to_owned())
+        })
+        .unwrap_or_else(|| "unknown".to_owned())
+}
+
+pub struct Comparison {
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub before: Option<Duration>,
+    pub after: Duration,
+    pub threshold: f64,
+}
+
+impl Comparison {
+    /// Relative change of the median in percent.
+    pub fn change(&self) -> Option<f64> {
+        self.before.map(|before| {
+            (self.after.as_secs_f64() - before.as_secs_f64()) / before.as_secs_f64() * 100.0
+        })
+    }
+
+    pub fn regressed(&self) -> bool {
+        self.change().is_some_and(|c| c > self.threshold)
+    }
+}
+
+impl Display for Comparison {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Day {} part {}: ", self.day, self.part)?;
+        match (self.before, self.change()) {
+            (Some(before), Some(change)) => {
+                write!(
+                    f,
+                    "median {} -> {} ({:+.1}%)",
+                    Human(before),
+                    Human(self.after),
+                    change
+                )?;
+                if self.regressed() {
+                    write!(f, " REGRESSED")?;
+                }
+                Ok(())
+            }
+            _ => write!(f, "median {} (no baseline)", Human(self.after)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(year: u16, day: usize, part: u8, median: u64) -> BenchReport {
+        let median = Duration::from_micros(median);
+        BenchReport {
+            year,
+            day,
+            part,
+            iterations: 10,
+            input_len: 100,
+            stats: Stats {
+                min: median,
+                median,
+                mean: median,
+                stddev: Duration::ZERO,
+            },
+        }
+    }
+
+    #[test]
+    fn flags_regressions_beyond_threshold() {
+        let baseline = Baseline {
+            environment: Environment::default(),
+            results: vec![
+                Record::from(&report(2022, 4, 1, 100)),
+                Record::from(&report(2022, 4, 2, 100)),
+            ],
+        };
+        let comparisons = baseline.compare(
+            &[
+                report(2022, 4, 1, 105),
+                report(2022, 4, 2, 120),
+                report(2022, 6, 1, 10),
+                report(2023, 4, 2, 1000),
+            ],
+            10.0,
+        );
+
+        let regressed = comparisons
+            .iter()
+            .map(Comparison::regressed)
+            .collect::<Vec<_>>();
+        assert_eq!(regressed, [false, true, false, false]);
+        assert!(comparisons[3].before.is_none());
+        assert_eq!(
+            comparisons[1].to_string(),
+            "Day 4 part 2: median 100.00µs -> 120.00µs (+20.0%) REGRESSED"
+        );
+        assert_eq!(
+            comparisons[2].to_string(),
+            "Day 6 part 1: median 10.00µs (no baseline)"
+        );
+    }
+}
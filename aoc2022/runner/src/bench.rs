@@ -0,0 +1,128 @@
+use std::{fmt::Display, hint::black_box, time::Duration};
+
+use crate::{
+    run_part,
+    timing::{timed, Human},
+    Day,
+};
+
+pub struct BenchConfig {
+    pub iterations: usize,
+    pub warmup: usize,
+}
+
+/// Summary statistics over the samples of a benchmark.
+#[derive(Debug, Clone, Copy)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &mut [Duration]) -> Self {
+        assert!(!samples.is_empty(), "no samples to compute stats from");
+        samples.sort();
+        let len = samples.len();
+        let median = if len.is_multiple_of(2) {
+            (samples[len / 2 - 1] + samples[len / 2]) / 2
+        } else {
+            samples[len / 2]
+        };
+        let mean = samples.iter().map(Duration::as_secs_f64).sum::<f64>() / len as f64;
+        let variance = samples
+            .iter()
+            .map(|s| (s.as_secs_f64() - mean).powi(2))
+            .sum::<f64>()
+            / len as f64;
+
+        Self {
+            min: samples[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+pub struct BenchReport {
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub iterations: usize,
+    pub input_len: usize,
+    pub stats: Stats,
+}
+
+impl Display for BenchReport {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mean = self.stats.mean.as_secs_f64();
+        writeln!(
+            f,
+            "Day {} part {} ({} iterations)",
+            self.day, self.part, self.iterations
+        )?;
+        writeln!(
+            f,
+            "  min: {}  median: {}  mean: {} ± {}",
+            Human(self.stats.min),
+            Human(self.stats.median),
+            Human(self.stats.mean),
+            Human(self.stats.stddev)
+        )?;
+        write!(
+            f,
+            "  throughput: {:.2} runs/s, {:.2} MiB/s",
+            1.0 / mean,
+            self.input_len as f64 / mean / (1024.0 * 1024.0)
+        )
+    }
+}
+
+/// Repeatedly runs a part of `day` from `year` on the same input, discarding
+/// the first `warmup` runs. The input is only parsed once, up front.
+pub fn bench(
+    year: u16,
+    day: &dyn Day,
+    part: u8,
+    input: &str,
+    config: &BenchConfig,
+) -> anyhow::Result<BenchReport> {
+    anyhow::ensure!(config.iterations > 0, "at least one iteration is needed");
+    let parsed = day.parse_input(input)?;
+    for _ in 0..config.warmup {
+        black_box(run_part(day, part, black_box(&parsed))?);
+    }
+
+    let mut samples = Vec::with_capacity(config.iterations);
+    for _ in 0..config.iterations {
+        let (res, elapsed) = timed(|| run_part(day, part, black_box(&parsed)));
+        black_box(res?);
+        samples.push(elapsed);
+    }
+
+    Ok(BenchReport {
+        year,
+        day: day.day(),
+        part,
+        iterations: config.iterations,
+        input_len: input.len(),
+        stats: Stats::from_samples(&mut samples),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats() {
+        let mut samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&mut samples);
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean, Duration::from_micros(2500));
+        assert_eq!(stats.stddev.as_micros(), 1118);
+    }
+}
@@ -0,0 +1,242 @@
+use std::path::PathBuf;
+
+use clap::{Arg, ArgAction, Command};
+
+pub(crate) fn command() -> Command {
+    Command::new("Advent Of Code")
+        .author(env!("CARGO_PKG_AUTHORS"))
+        .version(env!("CARGO_PKG_VERSION"))
+        .about("Runner for advent of code")
+        .arg(
+            Arg::new("config")
+                .long("config")
+                .global(true)
+                .help("Configuration file, `aoc.toml` in the working directory or a parent by default")
+                .value_parser(clap::value_parser!(PathBuf)),
+        )
+        .arg(
+            Arg::new("verbose")
+                .long("verbose")
+                .short('v')
+                .global(true)
+                .help("Show diagnostics from days on stderr, -vv and -vvv for more detail")
+                .action(ArgAction::Count),
+        )
+        .arg(
+            Arg::new("log-day")
+                .long("log-day")
+                .global(true)
+                .help("Only show diagnostics from this day, can be repeated")
+                .action(ArgAction::Append)
+                .value_parser(clap::value_parser!(usize)),
+        )
+        .arg(
+            Arg::new("year")
+                .long("year")
+                .global(true)
+                .help("Year to run, defaults to the latest registered")
+                .value_parser(clap::value_parser!(u16)),
+        )
+        .arg(
+            Arg::new("raw-input")
+                .long("raw-input")
+                .global(true)
+                .help("Pass inputs to days as is, without normalizing them")
+                .action(ArgAction::SetTrue),
+        )
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .global(true)
+                .value_parser(["text", "json"])
+                .help("Output format, multi-day runs print JSON Lines [default: text]"),
+        )
+        .arg(
+            Arg::new("jobs")
+                .long("jobs")
+                .short('j')
+                .global(true)
+                .value_parser(clap::value_parser!(usize))
+                .help("Number of threads days of multi-day runs are spread over [default: 1]"),
+        )
+        .arg(
+            Arg::new("timeout")
+                .long("timeout")
+                .global(true)
+                .help("Give up on a part after this long, e.g. `500ms`, `10s` or `2m`"),
+        )
+        .subcommand(Command::new("all").about("Run both parts of every configured day"))
+        .subcommand(
+            Command::new("list").about(
+                "Run every configured day and show which parts are done as a star chart, \
+                 JSON Lines give the status of each part",
+            ),
+        )
+        .subcommand(
+            Command::new("examples")
+                .about("Check every configured day against the examples of its puzzle")
+                .arg(
+                    Arg::new("day")
+                        .help("Only check the examples of this day")
+                        .value_parser(clap::value_parser!(usize)),
+                ),
+        )
+        .subcommand(
+            Command::new("new")
+                .about("Generate a day from the template and register it")
+                .arg(
+                    Arg::new("day")
+                        .required(true)
+                        .value_parser(clap::value_parser!(usize)),
+                ),
+        )
+        .subcommand(
+            Command::new("verify")
+                .about("Check the answers of every configured day against the expected ones")
+                .arg(
+                    Arg::new("answers")
+                        .long("answers")
+                        .default_value("answers.toml")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("junit")
+                        .long("junit")
+                        .help("Also write a JUnit XML report to this file")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new("tui")
+                .about("Browse and re-run every configured day in an interactive dashboard")
+                .arg(
+                    Arg::new("answers")
+                        .long("answers")
+                        .help("Expected answers telling solved parts from wrong ones, if present")
+                        .default_value("answers.toml")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                ),
+        )
+        .subcommand(
+            Command::new("watch")
+                .about(
+                    "Re-run a day whenever its input or one of the given paths changes, \
+                     the day's code is not rebuilt",
+                )
+                .arg(
+                    Arg::new("day")
+                        .required(true)
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    Arg::new("input")
+                        .help("Input file, the conventional one for the day by default")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("part")
+                        .short('p')
+                        .help("Only run this part, both parts are run otherwise")
+                        .value_parser(clap::value_parser!(u8).range(1..=2)),
+                )
+                .arg(
+                    Arg::new("path")
+                        .long("path")
+                        .help(
+                            "Also re-run when this data file, or any file under this directory, \
+                             changes. Code changes need a rebuild and restart",
+                        )
+                        .action(ArgAction::Append)
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("interval")
+                        .long("interval")
+                        .help("How often to check for changes")
+                        .default_value("500ms"),
+                ),
+        )
+        .subcommand(
+            Command::new("bench")
+                .about("Benchmark a day by running it repeatedly on the same input")
+                .arg(
+                    Arg::new("day")
+                        .required(true)
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(input_arg())
+                .arg(input_str_arg())
+                .arg(
+                    Arg::new("part")
+                        .short('p')
+                        .help("Only benchmark this part, both parts are run otherwise")
+                        .value_parser(clap::value_parser!(u8).range(1..=2)),
+                )
+                .arg(
+                    Arg::new("iterations")
+                        .long("iterations")
+                        .default_value("100")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    Arg::new("warmup")
+                        .long("warmup")
+                        .default_value("3")
+                        .value_parser(clap::value_parser!(usize)),
+                )
+                .arg(
+                    Arg::new("baseline")
+                        .long("baseline")
+                        .help("Baseline file used by --save and --compare")
+                        .default_value("bench-baseline.json")
+                        .value_parser(clap::value_parser!(PathBuf)),
+                )
+                .arg(
+                    Arg::new("save")
+                        .long("save")
+                        .help("Save the results to the baseline file")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("compare")
+                        .long("compare")
+                        .help("Compare the results against the baseline file, failing on regressions")
+                        .action(ArgAction::SetTrue),
+                )
+                .arg(
+                    Arg::new("threshold")
+                        .long("threshold")
+                        .help("Slowdown in percent above which a part is flagged")
+                        .default_value("10")
+                        .value_parser(clap::value_parser!(f64)),
+                ),
+        )
+        .arg(Arg::new("day").value_parser(clap::value_parser!(usize)))
+        .arg(input_arg())
+        .arg(input_str_arg())
+        .arg(
+            Arg::new("part")
+                .short('p')
+                .help("Part to run [default: 1]")
+                .value_parser(clap::value_parser!(u8).range(1..=2)),
+        )
+        .arg(
+            Arg::new("time")
+                .long("time")
+                .help("Print how long reading the input and solving took to stderr")
+                .action(ArgAction::SetTrue),
+        )
+}
+
+pub(crate) fn input_arg() -> Arg {
+    Arg::new("input")
+        .help("Input file, `-` reads from stdin")
+        .value_parser(clap::value_parser!(PathBuf))
+}
+
+pub(crate) fn input_str_arg() -> Arg {
+    Arg::new("input-str")
+        .long("input-str")
+        .help("Use the given string as input")
+        .conflicts_with("input")
+}
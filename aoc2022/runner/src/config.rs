@@ -0,0 +1,132 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Deserializer};
+
+use crate::{format::Format, normalize::Normalize, timing::parse_duration};
+
+/// Name of the configuration file looked up from the working directory up.
+pub const CONFIG_FILE: &str = "aoc.toml";
+
+/// Project defaults, overridden by command line flags.
+///
+/// ```toml
+/// year = 2022
+/// inputs = ["inputs", "../private-inputs"]
+/// format = "json"
+/// timeout = "10s"
+/// jobs = 4
+/// part = 2
+///
+/// [normalize]
+/// trailing_newline = false
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Config {
+    pub year: Option<u16>,
+    /// Directories inputs are looked up in, relative to the file.
+    pub inputs: Vec<PathBuf>,
+    pub format: Option<Format>,
+    #[serde(deserialize_with = "duration")]
+    pub timeout: Option<Duration>,
+    pub jobs: Option<usize>,
+    /// Part run when a day is given without `-p`.
+    pub part: Option<u8>,
+    /// Steps left out default to enabled.
+    pub normalize: Option<Normalize>,
+}
+
+impl Config {
+    /// Loads the closest `aoc.toml` in `dir` or its ancestors, if any.
+    pub fn discover(dir: &Path) -> anyhow::Result<Option<Self>> {
+        dir.ancestors()
+            .map(|dir| dir.join(CONFIG_FILE))
+            .find(|path| path.is_file())
+            .map(|path| Self::load(&path))
+            .transpose()
+    }
+
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("could not read {}: {}", path.display(), e))?;
+        let mut config = Self::parse(&contents)
+            .map_err(|e| anyhow::anyhow!("invalid config {}: {}", path.display(), e))?;
+        if let Some(dir) = path.parent() {
+            config.inputs = config.inputs.iter().map(|input| dir.join(input)).collect();
+        }
+        Ok(config)
+    }
+
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        let config: Self = toml::from_str(contents)?;
+        if let Some(part) = config.part.filter(|part| !(1..=2).contains(part)) {
+            anyhow::bail!("part must be 1 or 2, got {}", part)
+        }
+        Ok(config)
+    }
+}
+
+fn duration<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Duration>, D::Error> {
+    let duration = String::deserialize(deserializer)?;
+    parse_duration(&duration)
+        .map(Some)
+        .map_err(serde::de::Error::custom)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, process};
+
+    use super::*;
+
+    #[test]
+    fn parses_defaults() -> anyhow::Result<()> {
+        let config = Config::parse(
+            r#"
+            year = 2021
+            format = "json"
+            timeout = "1.5s"
+            part = 2
+
+            [normalize]
+            crlf = false
+            "#,
+        )?;
+        assert_eq!(config.year, Some(2021));
+        assert_eq!(config.format, Some(Format::Json));
+        assert_eq!(config.timeout, Some(Duration::from_millis(1500)));
+        assert_eq!(config.part, Some(2));
+        assert_eq!(
+            config.normalize,
+            Some(Normalize {
+                crlf: false,
+                ..Normalize::ALL
+            })
+        );
+        assert!(config.jobs.is_none());
+
+        assert!(Config::parse("part = 3").is_err());
+        assert!(Config::parse("timeout = \"soon\"").is_err());
+        assert!(Config::parse("timeout = \"99999999999999999999999m\"").is_err());
+        assert!(Config::parse("input = [\"typo\"]").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn discovers_upward() -> anyhow::Result<()> {
+        let root = env::temp_dir().join(format!("aoc-config-{}", process::id()));
+        let nested = root.join("aoc22").join("src");
+        fs::create_dir_all(&nested)?;
+        fs::write(root.join(CONFIG_FILE), "inputs = [\"inputs\"]")?;
+
+        let config = Config::discover(&nested)?.expect("config in a parent directory");
+        assert_eq!(config.inputs, [root.join("inputs")]);
+
+        fs::remove_dir_all(&root)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use std::{any::Any, fmt::Display, sync::Arc};
+
+use crate::{answer::Answer, examples::Example, normalize::Normalize};
+
+/// Error returned by parts that are not solved yet.
+#[derive(Debug, Clone, Copy)]
+pub struct NotImplemented;
+
+impl Display for NotImplemented {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "not implemented")
+    }
+}
+
+impl std::error::Error for NotImplemented {}
+
+/// Input parsed by [`Day::parse_input`], shared by both parts.
+pub type Parsed = Arc<dyn Any + Send + Sync>;
+
+pub trait Day: Send + Sync {
+    /// Defaults to [`NotImplemented`] until the part is solved.
+    fn part1(&self, _input: &str) -> anyhow::Result<Answer> {
+        Err(NotImplemented.into())
+    }
+
+    /// Defaults to [`NotImplemented`] until the part is solved.
+    fn part2(&self, _input: &str) -> anyhow::Result<Answer> {
+        Err(NotImplemented.into())
+    }
+
+    fn day(&self) -> usize;
+
+    /// Normalization steps this day accepts, days with whitespace sensitive
+    /// inputs can opt out of some or all of them.
+    fn normalize(&self) -> Normalize {
+        Normalize::ALL
+    }
+
+    /// Examples from the puzzle description, checked by `examples`.
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+
+    /// Parses the input once for both parts. Days parsing in each part keep
+    /// the input as is.
+    fn parse_input(&self, input: &str) -> anyhow::Result<Parsed> {
+        Ok(Arc::new(input.to_owned()))
+    }
+
+    /// Runs a part on the output of [`Day::parse_input`].
+    fn solve(&self, part: u8, parsed: &Parsed) -> anyhow::Result<Answer> {
+        let input = parsed
+            .downcast_ref::<String>()
+            .expect("input parsed by Day::parse_input");
+        match part {
+            1 => self.part1(input),
+            2 => self.part2(input),
+            _ => unreachable!(),
+        }
+    }
+}
+
+/// A day that parses its input once into [`Solution::Input`], which is then
+/// handed to both parts. Every solution is a [`Day`].
+pub trait Solution: Send + Sync {
+    type Input: Send + Sync + 'static;
+
+    fn parse(&self, input: &str) -> anyhow::Result<Self::Input>;
+
+    /// Defaults to [`NotImplemented`] until the part is solved.
+    fn part1(&self, _input: &Self::Input) -> anyhow::Result<Answer> {
+        Err(NotImplemented.into())
+    }
+
+    /// Defaults to [`NotImplemented`] until the part is solved.
+    fn part2(&self, _input: &Self::Input) -> anyhow::Result<Answer> {
+        Err(NotImplemented.into())
+    }
+
+    fn day(&self) -> usize;
+
+    /// See [`Day::normalize`].
+    fn normalize(&self) -> Normalize {
+        Normalize::ALL
+    }
+
+    /// See [`Day::examples`].
+    fn examples(&self) -> &'static [Example] {
+        &[]
+    }
+}
+
+impl<S: Solution> Day for S {
+    fn part1(&self, input: &str) -> anyhow::Result<Answer> {
+        Solution::part1(self, &self.parse(input)?)
+    }
+
+    fn part2(&self, input: &str) -> anyhow::Result<Answer> {
+        Solution::part2(self, &self.parse(input)?)
+    }
+
+    fn day(&self) -> usize {
+        Solution::day(self)
+    }
+
+    fn normalize(&self) -> Normalize {
+        Solution::normalize(self)
+    }
+
+    fn examples(&self) -> &'static [Example] {
+        Solution::examples(self)
+    }
+
+    fn parse_input(&self, input: &str) -> anyhow::Result<Parsed> {
+        Ok(Arc::new(self.parse(input)?))
+    }
+
+    fn solve(&self, part: u8, parsed: &Parsed) -> anyhow::Result<Answer> {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("input parsed by Day::parse_input");
+        match part {
+            1 => Solution::part1(self, input),
+            2 => Solution::part2(self, input),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    #[derive(Default)]
+    struct Sum {
+        parses: AtomicUsize,
+    }
+
+    impl Solution for Sum {
+        type Input = Vec<i64>;
+
+        fn parse(&self, input: &str) -> anyhow::Result<Self::Input> {
+            self.parses.fetch_add(1, Ordering::Relaxed);
+            Ok(input.lines().map(str::parse).collect::<Result<_, _>>()?)
+        }
+
+        fn part1(&self, input: &Self::Input) -> anyhow::Result<Answer> {
+            Ok(input.iter().sum::<i64>().into())
+        }
+
+        fn day(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn parses_once_for_both_parts() -> anyhow::Result<()> {
+        let sum = Sum::default();
+        let day: &dyn Day = &sum;
+        let parsed = day.parse_input("1\n2\n3")?;
+        assert_eq!(day.solve(1, &parsed)?, Answer::Int(6));
+        assert!(day.solve(2, &parsed).unwrap_err().is::<NotImplemented>());
+        assert_eq!(sum.parses.load(Ordering::Relaxed), 1);
+        assert_eq!(day.part1("4\n5")?, Answer::Int(9));
+        assert!(day.parse_input("x").is_err());
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use std::io::{self, IsTerminal};
+
+use tracing_subscriber::EnvFilter;
+
+/// Name of the span parts run in, carrying the `day` and `part` numbers.
+pub(crate) const SPAN: &str = "day";
+
+/// Writes diagnostics emitted by days through [`tracing`] to stderr, keeping
+/// answers on stdout clean. `RUST_LOG` takes precedence over the flags.
+pub(crate) fn init(verbosity: u8, days: &[usize]) {
+    let filter = EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| EnvFilter::new(directives(verbosity, days)));
+    // a subscriber may already be set, e.g. when the runner is embedded
+    let _ = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .with_ansi(io::stderr().is_terminal())
+        .with_target(false)
+        .without_time()
+        .try_init();
+}
+
+/// Filter directives for `-v` repeated `verbosity` times, only for `days`
+/// when some are given.
+fn directives(verbosity: u8, days: &[usize]) -> String {
+    let level = |verbosity| match verbosity {
+        0 => "warn",
+        1 => "info",
+        2 => "debug",
+        _ => "trace",
+    };
+    if days.is_empty() {
+        return level(verbosity).to_owned();
+    }
+    std::iter::once(level(0).to_owned())
+        .chain(
+            days.iter()
+                .map(|day| format!("[{}{{day={}}}]={}", SPAN, day, level(verbosity.max(1)))),
+        )
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_directives() {
+        assert_eq!(directives(0, &[]), "warn");
+        assert_eq!(directives(2, &[]), "debug");
+        assert_eq!(directives(0, &[7]), "warn,[day{day=7}]=info");
+        assert_eq!(
+            directives(3, &[7, 12]),
+            "warn,[day{day=7}]=trace,[day{day=12}]=trace"
+        );
+        for directive in directives(2, &[7]).split(',') {
+            assert!(directive
+                .parse::<tracing_subscriber::filter::Directive>()
+                .is_ok());
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use std::fmt::Display;
+
+use crate::{answer::Answer, summary::PartResult, table::write_table, verify::Verdict};
+
+/// An example from a puzzle description along with the answers it gives.
+#[derive(Debug, Clone, Copy)]
+pub struct Example {
+    pub input: &'static str,
+    pub part1: Option<&'static str>,
+    pub part2: Option<&'static str>,
+}
+
+impl Example {
+    pub const fn new(input: &'static str, part1: &'static str, part2: &'static str) -> Self {
+        Self {
+            input,
+            part1: Some(part1),
+            part2: Some(part2),
+        }
+    }
+
+    /// An example only given for the first part.
+    pub const fn part1(input: &'static str, part1: &'static str) -> Self {
+        Self {
+            input,
+            part1: Some(part1),
+            part2: None,
+        }
+    }
+
+    pub fn expected(&self, part: u8) -> Option<Answer> {
+        match part {
+            1 => self.part1.map(Answer::from),
+            2 => self.part2.map(Answer::from),
+            _ => None,
+        }
+    }
+}
+
+/// Outcome of a part run on an example, along with the example's index
+/// within its day.
+pub struct ExampleResult {
+    pub example: usize,
+    pub result: PartResult,
+    pub verdict: Verdict,
+}
+
+/// Results of running the examples of one or more days.
+pub struct Examples {
+    pub year: u16,
+    pub results: Vec<ExampleResult>,
+}
+
+impl Examples {
+    /// Checks the results of running `example` against its answers, parts
+    /// without an answer are left out.
+    pub(crate) fn push(&mut self, index: usize, example: &Example, results: Vec<PartResult>) {
+        for result in results {
+            if let Some(expected) = example.expected(result.part) {
+                let verdict = Verdict::check(&result, Some(&expected));
+                self.results.push(ExampleResult {
+                    example: index,
+                    result,
+                    verdict,
+                });
+            }
+        }
+    }
+
+    pub fn failures(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| r.verdict.is_failure())
+            .count()
+    }
+
+    pub fn success(&self) -> bool {
+        self.failures() == 0
+    }
+}
+
+impl Display for Examples {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_table(
+            f,
+            ["Day", "Example", "Part", "Result"],
+            self.results.iter().map(|r| {
+                [
+                    r.result.day.to_string(),
+                    r.example.to_string(),
+                    r.result.part.to_string(),
+                    r.verdict.to_string(),
+                ]
+            }),
+        )?;
+
+        for r in &self.results {
+            r.verdict.write_details(
+                f,
+                format_args!(
+                    "Day {} example {} part {}",
+                    r.result.day, r.example, r.result.part
+                ),
+            )?;
+        }
+        write!(
+            f,
+            "\n\n{} of {} examples failed",
+            self.failures(),
+            self.results.len()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::timing::Timings;
+
+    #[test]
+    fn checks_given_answers() {
+        let timings = Timings::default();
+        let example = Example::part1("1\n2", "3");
+        let mut examples = Examples {
+            year: 2022,
+            results: Vec::new(),
+        };
+        examples.push(
+            1,
+            &example,
+            vec![
+                PartResult::new(2022, 1, 1, Ok(4.into()), timings),
+                PartResult::new(2022, 1, 2, Ok(2.into()), timings),
+            ],
+        );
+        assert_eq!(examples.results.len(), 1);
+        assert!(!examples.success());
+        assert_eq!(
+            examples.to_string(),
+            "Day | Example | Part | Result\n\
+             1   | 1       | 1    | FAIL\n\
+             \n\
+             Day 1 example 1 part 1:\n  \
+             expected: 3\n  \
+             actual:   4\n\
+             \n\
+             1 of 1 examples failed"
+        );
+    }
+}
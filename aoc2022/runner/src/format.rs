@@ -0,0 +1,255 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answer::Answer,
+    baseline::Comparison,
+    bench::BenchReport,
+    examples::Examples,
+    summary::{PartResult, Status, Summary},
+    verify::{Verdict, Verification},
+};
+
+/// How results are printed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Format {
+    Text,
+    /// One JSON object per part, one per line for multi-day runs.
+    Json,
+}
+
+impl std::str::FromStr for Format {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => anyhow::bail!("unknown format: {}", s),
+        }
+    }
+}
+
+#[derive(Serialize)]
+struct JsonTimings {
+    read_ns: u64,
+    parse_ns: u64,
+    solve_ns: u64,
+}
+
+#[derive(Serialize)]
+struct JsonResult<'a> {
+    year: u16,
+    day: usize,
+    part: u8,
+    answer: Option<&'a Answer>,
+    status: &'static str,
+    error: Option<&'a str>,
+    timings: Option<JsonTimings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    example: Option<usize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    verdict: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    expected: Option<&'a Answer>,
+}
+
+impl<'a> From<&'a PartResult> for JsonResult<'a> {
+    fn from(result: &'a PartResult) -> Self {
+        let (status, answer, error) = match &result.status {
+            Status::Ok => ("ok", result.answer.as_ref(), None),
+            Status::Failed(msg) => ("error", None, Some(msg.as_str())),
+            Status::Panicked(msg) => ("panic", None, Some(msg.as_str())),
+            Status::TimedOut(msg) => ("timeout", None, Some(msg.as_str())),
+            Status::NotImplemented => ("not_implemented", None, None),
+            Status::NoInput => ("no_input", None, None),
+        };
+        Self {
+            year: result.year,
+            day: result.day,
+            part: result.part,
+            answer,
+            status,
+            error,
+            timings: result.timings.map(|t| JsonTimings {
+                read_ns: t.read.as_nanos() as u64,
+                parse_ns: t.parse.as_nanos() as u64,
+                solve_ns: t.solve.as_nanos() as u64,
+            }),
+            example: None,
+            verdict: None,
+            expected: None,
+        }
+    }
+}
+
+pub fn part_json(result: &PartResult) -> String {
+    serde_json::to_string(&JsonResult::from(result)).expect("results are serializable")
+}
+
+/// JSON Lines, one object per part.
+pub fn summary_json(summary: &Summary) -> String {
+    summary.iter().map(part_json).collect::<Vec<_>>().join("\n")
+}
+
+/// JSON Lines, one object per part including its verdict.
+pub fn verification_json(verification: &Verification) -> String {
+    verification
+        .results
+        .iter()
+        .map(|(result, verdict)| verdict_json(JsonResult::from(result), verdict))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// JSON Lines, one object per part of each example including its verdict.
+pub fn examples_json(examples: &Examples) -> String {
+    examples
+        .results
+        .iter()
+        .map(|r| {
+            let mut json = JsonResult::from(&r.result);
+            json.example = Some(r.example);
+            verdict_json(json, &r.verdict)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn verdict_json<'a>(mut json: JsonResult<'a>, verdict: &'a Verdict) -> String {
+    json.verdict = Some(verdict.to_string());
+    if let Verdict::Fail { expected, .. } = verdict {
+        json.expected = Some(expected);
+    }
+    serde_json::to_string(&json).expect("results are serializable")
+}
+
+#[derive(Serialize)]
+struct JsonBench {
+    year: u16,
+    day: usize,
+    part: u8,
+    iterations: usize,
+    input_len: usize,
+    min_ns: u64,
+    median_ns: u64,
+    mean_ns: u64,
+    stddev_ns: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    baseline: Option<JsonComparison>,
+}
+
+#[derive(Serialize)]
+struct JsonComparison {
+    median_ns: Option<u64>,
+    change_pct: Option<f64>,
+    regressed: bool,
+}
+
+/// JSON Lines, one object per benchmarked part. `comparisons`, if any, are
+/// in the same order as `reports`.
+pub fn bench_json(reports: &[BenchReport], comparisons: &[Comparison]) -> String {
+    let comparisons = comparisons.iter().map(Some).chain(std::iter::repeat(None));
+    reports
+        .iter()
+        .zip(comparisons)
+        .map(|(report, comparison)| {
+            let json = JsonBench {
+                year: report.year,
+                day: report.day,
+                part: report.part,
+                iterations: report.iterations,
+                input_len: report.input_len,
+                min_ns: report.stats.min.as_nanos() as u64,
+                median_ns: report.stats.median.as_nanos() as u64,
+                mean_ns: report.stats.mean.as_nanos() as u64,
+                stddev_ns: report.stats.stddev.as_nanos() as u64,
+                baseline: comparison.map(|c| JsonComparison {
+                    median_ns: c.before.map(|b| b.as_nanos() as u64),
+                    change_pct: c.change(),
+                    regressed: c.regressed(),
+                }),
+            };
+            serde_json::to_string(&json).expect("reports are serializable")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{bench::Stats, timing::Timings, watchdog::TimedOut};
+
+    #[test]
+    fn json_lines() {
+        let mut summary = Summary::default();
+        summary.push(PartResult::new(
+            2022,
+            8,
+            1,
+            Ok(21u64.into()),
+            Timings {
+                read: Duration::from_nanos(40),
+                parse: Duration::from_nanos(100),
+                solve: Duration::from_nanos(2000),
+            },
+        ));
+        summary.push(PartResult::no_input(2022, 9, 1));
+        summary.push(PartResult::new(
+            2022,
+            10,
+            2,
+            Err(TimedOut(Duration::from_secs(1)).into()),
+            Timings::default(),
+        ));
+
+        assert_eq!(
+            summary_json(&summary),
+            r#"{"year":2022,"day":8,"part":1,"answer":21,"status":"ok","error":null,"timings":{"read_ns":40,"parse_ns":100,"solve_ns":2000}}
+{"year":2022,"day":9,"part":1,"answer":null,"status":"no_input","error":null,"timings":null}
+{"year":2022,"day":10,"part":2,"answer":null,"status":"timeout","error":"timed out after 1.00s","timings":{"read_ns":0,"parse_ns":0,"solve_ns":0}}"#
+        );
+    }
+
+    #[test]
+    fn bench_json_lines() {
+        let median = Duration::from_micros(120);
+        let report = BenchReport {
+            year: 2022,
+            day: 4,
+            part: 2,
+            iterations: 10,
+            input_len: 100,
+            stats: Stats {
+                min: median,
+                median,
+                mean: median,
+                stddev: Duration::ZERO,
+            },
+        };
+        let comparison = Comparison {
+            year: 2022,
+            day: 4,
+            part: 2,
+            before: Some(Duration::from_micros(100)),
+            after: median,
+            threshold: 10.0,
+        };
+
+        let line = r#"{"year":2022,"day":4,"part":2,"iterations":10,"input_len":100,"min_ns":120000,"median_ns":120000,"mean_ns":120000,"stddev_ns":0"#;
+        assert_eq!(
+            bench_json(std::slice::from_ref(&report), &[]),
+            format!("{}}}", line)
+        );
+        assert_eq!(
+            bench_json(&[report], &[comparison]),
+            format!(
+                r#"{},"baseline":{{"median_ns":100000,"change_pct":20.0,"regressed":true}}}}"#,
+                line
+            )
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    env,
+    fs::File,
+    io::{self, Read},
+    path::{Path, PathBuf},
+};
+
+use itertools::Itertools;
+
+/// Environment variable overriding where inputs are looked up.
+pub const INPUT_DIR_VAR: &str = "AOC_INPUT_DIR";
+
+/// Finds puzzle inputs by convention, as `<dir>/<year>/dayNN.txt`.
+#[derive(Debug, Clone)]
+pub struct InputLocator {
+    dirs: Vec<PathBuf>,
+}
+
+impl Default for InputLocator {
+    fn default() -> Self {
+        Self::new(vec![PathBuf::from("inputs")])
+    }
+}
+
+impl InputLocator {
+    pub fn new(dirs: Vec<PathBuf>) -> Self {
+        Self { dirs }
+    }
+
+    /// Uses the directories listed in `AOC_INPUT_DIR`, falling back to
+    /// `inputs` in the working directory.
+    pub fn from_env() -> Self {
+        match env::var_os(INPUT_DIR_VAR) {
+            Some(dirs) if !dirs.is_empty() => Self::new(env::split_paths(&dirs).collect()),
+            _ => Self::default(),
+        }
+    }
+
+    /// Every path an input for the day could be at, in lookup order.
+    pub fn candidates(&self, year: u16, day: usize) -> Vec<PathBuf> {
+        self.dirs
+            .iter()
+            .flat_map(|dir| {
+                let dir = dir.join(year.to_string());
+                [
+                    dir.join(format!("day{:02}.txt", day)),
+                    dir.join(format!("day{}.txt", day)),
+                ]
+            })
+            .unique()
+            .collect()
+    }
+
+    pub fn find(&self, year: u16, day: usize) -> anyhow::Result<PathBuf> {
+        let candidates = self.candidates(year, day);
+        match candidates.iter().find(|path| path.is_file()) {
+            Some(path) => Ok(path.clone()),
+            None => anyhow::bail!(
+                "No input found for {} day {}, tried:\n{}",
+                year,
+                day,
+                candidates
+                    .iter()
+                    .map(|path| format!("  {}", path.display()))
+                    .join("\n")
+            ),
+        }
+    }
+}
+
+/// Where the puzzle input of a run comes from.
+#[derive(Debug, Clone)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+    Inline(String),
+}
+
+impl InputSource {
+    /// Interprets a path given on the command line, where `-` means stdin.
+    pub fn from_arg(path: &Path) -> Self {
+        if path == Path::new("-") {
+            Self::Stdin
+        } else {
+            Self::File(path.to_owned())
+        }
+    }
+
+    pub fn read(&self) -> anyhow::Result<String> {
+        match self {
+            Self::File(path) => read_input(path),
+            Self::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+            Self::Inline(input) => Ok(input.clone()),
+        }
+    }
+}
+
+pub fn read_input(path: &Path) -> anyhow::Result<String> {
+    let mut input_file = File::open(path)
+        .map_err(|e| anyhow::anyhow!("could not open {}: {}", path.display(), e))?;
+    let mut input = String::new();
+    input_file.read_to_string(&mut input)?;
+    Ok(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lists_tried_paths() {
+        let locator = InputLocator::new(vec!["a".into(), "b".into()]);
+        assert_eq!(
+            locator.find(2022, 8).err().unwrap().to_string(),
+            "No input found for 2022 day 8, tried:\n  \
+             a/2022/day08.txt\n  \
+             a/2022/day8.txt\n  \
+             b/2022/day08.txt\n  \
+             b/2022/day8.txt"
+        );
+        assert_eq!(locator.candidates(2022, 12).len(), 2);
+    }
+}
@@ -0,0 +1,148 @@
+use std::fmt::Write;
+
+use crate::{
+    summary::Status,
+    verify::{Verdict, Verification},
+};
+
+/// Renders a verification as a JUnit XML report with one testcase per part.
+pub fn report(verification: &Verification) -> String {
+    let year = verification.year;
+    let total = verification
+        .results
+        .iter()
+        .filter_map(|(result, _)| result.timings)
+        .map(|t| t.solve)
+        .sum::<std::time::Duration>();
+    let count = |f: fn(&Verdict) -> bool| verification.results.iter().filter(|(_, v)| f(v)).count();
+    let failures = count(|v| matches!(v, Verdict::Fail { .. }));
+    let errors = count(|v| matches!(v, Verdict::Error(_)));
+    let skipped = count(|v| matches!(v, Verdict::Missing | Verdict::NoInput));
+
+    let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    writeln!(
+        xml,
+        "<testsuites>\n  <testsuite name=\"aoc{}\" tests=\"{}\" failures=\"{}\" errors=\"{}\" skipped=\"{}\" time=\"{:.6}\">",
+        year,
+        verification.results.len(),
+        failures,
+        errors,
+        skipped,
+        total.as_secs_f64()
+    )
+    .unwrap();
+
+    for (result, verdict) in &verification.results {
+        let time = result.timings.map_or(0.0, |t| t.solve.as_secs_f64());
+        write!(
+            xml,
+            "    <testcase classname=\"aoc{}.day{:02}\" name=\"part{}\" time=\"{:.6}\"",
+            year, result.day, result.part, time
+        )
+        .unwrap();
+        match verdict {
+            Verdict::Pass => xml.push_str("/>\n"),
+            Verdict::Fail { expected, actual } => {
+                let expected = expected.to_string();
+                writeln!(
+                    xml,
+                    ">\n      <failure message=\"expected {}, got {}\">expected:\n{}\nactual:\n{}</failure>\n    </testcase>",
+                    escape(&expected),
+                    escape(actual),
+                    escape(&expected),
+                    escape(actual)
+                )
+                .unwrap();
+            }
+            Verdict::Error(status) => {
+                let message = match status {
+                    Status::Failed(msg) | Status::Panicked(msg) | Status::TimedOut(msg) => {
+                        msg.as_str()
+                    }
+                    _ => "",
+                };
+                writeln!(
+                    xml,
+                    ">\n      <error type=\"{}\" message=\"{}\">{}</error>\n    </testcase>",
+                    status,
+                    escape(message.lines().next().unwrap_or_default()),
+                    escape(message)
+                )
+                .unwrap();
+            }
+            Verdict::Missing => xml
+                .push_str(">\n      <skipped message=\"no expected answer\"/>\n    </testcase>\n"),
+            Verdict::NoInput => {
+                xml.push_str(">\n      <skipped message=\"no input\"/>\n    </testcase>\n")
+            }
+        }
+    }
+    xml.push_str("  </testsuite>\n</testsuites>\n");
+    xml
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::{
+        panic::Panicked,
+        summary::{PartResult, Summary},
+        timing::Timings,
+        verify::Answers,
+    };
+
+    #[test]
+    fn testcase_per_part() -> anyhow::Result<()> {
+        let answers = Answers::parse("[2022.5]\npart1 = \"CMZ\"\npart2 = \"MCD\"")?;
+        let timings = Timings {
+            solve: Duration::from_millis(1),
+            ..Timings::default()
+        };
+        let mut summary = Summary::default();
+        summary.push(PartResult::new(2022, 5, 1, Ok("CMZ".into()), timings));
+        summary.push(PartResult::new(2022, 5, 2, Ok("<MC>".into()), timings));
+        summary.push(PartResult::new(
+            2022,
+            6,
+            1,
+            Err(Panicked {
+                message: "boom".to_owned(),
+                location: Some("src/day6.rs:3:5".to_owned()),
+            }
+            .into()),
+            timings,
+        ));
+        let verification = Verification::new(2022, summary, &answers);
+
+        assert_eq!(
+            report(&verification),
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+<testsuites>
+  <testsuite name="aoc2022" tests="3" failures="1" errors="1" skipped="0" time="0.003000">
+    <testcase classname="aoc2022.day05" name="part1" time="0.001000"/>
+    <testcase classname="aoc2022.day05" name="part2" time="0.001000">
+      <failure message="expected MCD, got &lt;MC&gt;">expected:
+MCD
+actual:
+&lt;MC&gt;</failure>
+    </testcase>
+    <testcase classname="aoc2022.day06" name="part1" time="0.001000">
+      <error type="panic" message="panicked at src/day6.rs:3:5: boom">panicked at src/day6.rs:3:5: boom</error>
+    </testcase>
+  </testsuite>
+</testsuites>
+"#
+        );
+        Ok(())
+    }
+}
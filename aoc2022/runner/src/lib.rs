@@ -1,57 +1,701 @@
-use std::{fs::File, io::Read, path::PathBuf};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    sync::Arc,
+    time::Duration,
+};
 
-use clap::{Arg, Command};
+use clap::ArgMatches;
+use itertools::Itertools;
 
+mod answer;
+mod baseline;
+mod bench;
+mod cli;
+mod config;
+mod day;
+mod diagnostics;
+mod examples;
+mod format;
+mod input;
+mod junit;
+mod normalize;
+mod panic;
+mod parallel;
+mod registry;
+mod scaffold;
+mod summary;
+mod table;
+mod timing;
+mod tui;
+mod verify;
+mod watch;
+mod watchdog;
+
+#[doc(hidden)]
+pub use inventory;
+/// Diagnostics days can emit, shown on stderr with `-v`.
+pub use tracing;
+
+pub use answer::Answer;
+pub use baseline::{Baseline, Comparison, Environment, Record};
+pub use bench::{bench, BenchConfig, BenchReport, Stats};
+pub use config::{Config, CONFIG_FILE};
+pub use day::{Day, NotImplemented, Parsed, Solution};
+pub use examples::{Example, ExampleResult, Examples};
+pub use format::Format;
+pub use input::{read_input, InputLocator, InputSource, INPUT_DIR_VAR};
+pub use normalize::Normalize;
+pub use panic::Panicked;
+pub use registry::{registered, PartFn, Registered, Registration, Registry};
+pub use scaffold::Scaffold;
+pub use summary::{PartResult, Status, Summary};
+pub use timing::{parse_duration, timed, Human, Timings};
+pub use verify::{Answers, Verdict, Verification};
+pub use watchdog::{with_timeout, TimedOut};
+
+/// Days grouped by the year of the event they belong to.
 pub struct Runner {
-    days: Vec<Box<dyn Day>>,
+    years: BTreeMap<u16, Registry>,
+    scaffolds: BTreeMap<u16, Scaffold>,
+    inputs: InputLocator,
+    normalize: Normalize,
+    format: Format,
+    jobs: usize,
+    timeout: Option<Duration>,
+    /// Year run when none is given, the latest registered one otherwise.
+    default_year: Option<u16>,
+    default_part: u8,
 }
 
-pub trait Day {
-    fn part1(&self, input: &str) -> anyhow::Result<String>;
-    fn part2(&self, input: &str) -> anyhow::Result<String>;
-    fn day(&self) -> usize;
+/// Text produced by a run and whether it should be reported as a success.
+pub struct Output {
+    pub text: String,
+    pub success: bool,
+}
+
+impl From<String> for Output {
+    fn from(text: String) -> Self {
+        Self {
+            text,
+            success: true,
+        }
+    }
+}
+
+impl Output {
+    pub fn exit_code(&self) -> ExitCode {
+        if self.success {
+            ExitCode::SUCCESS
+        } else {
+            ExitCode::FAILURE
+        }
+    }
+}
+
+impl Default for Runner {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Runner {
-    pub fn new(days: Vec<Box<dyn Day>>) -> Self {
-        Self { days }
-    }
-
-    pub fn run(mut self) -> anyhow::Result<String> {
-        self.days.sort_by_key(|a| a.day());
-        let matches = Command::new("Advent Of Code")
-            .author("Rene Leveille")
-            .version("1")
-            .about("Runner for advent of code")
-            .arg(Arg::new("day").value_parser(clap::value_parser!(usize)))
-            .arg(Arg::new("input").value_parser(clap::value_parser!(PathBuf)))
-            .arg(
-                Arg::new("part")
-                    .short('p')
-                    .default_value("1")
-                    .value_parser(clap::value_parser!(u8).range(1..=2)),
-            )
-            .get_matches();
-        let Some(day) = matches.get_one::<usize>("day") else  {
-            anyhow::bail!("No day given")
-        };
-        let Some(input) = matches.get_one::<PathBuf>("input") else {
-            anyhow::bail!("no input file given")
-        };
-        let Some(part) = matches.get_one::<u8>("part") else {
+    pub fn new() -> Self {
+        Self {
+            years: BTreeMap::new(),
+            scaffolds: BTreeMap::new(),
+            inputs: InputLocator::from_env(),
+            normalize: Normalize::ALL,
+            format: Format::Text,
+            jobs: 1,
+            timeout: None,
+            default_year: None,
+            default_part: 1,
+        }
+    }
+
+    /// Sets the normalization applied to inputs of every day.
+    pub fn with_normalize(mut self, normalize: Normalize) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    /// Applies project defaults. Input directories from the environment take
+    /// precedence over the configured ones.
+    pub fn with_config(mut self, config: &Config) -> Self {
+        if !config.inputs.is_empty() && std::env::var_os(INPUT_DIR_VAR).is_none() {
+            self.inputs = InputLocator::new(config.inputs.clone());
+        }
+        if let Some(normalize) = config.normalize {
+            self.normalize = normalize;
+        }
+        if let Some(format) = config.format {
+            self.format = format;
+        }
+        if let Some(jobs) = config.jobs {
+            self.jobs = jobs;
+        }
+        if let Some(timeout) = config.timeout {
+            self.timeout = Some(timeout);
+        }
+        if let Some(part) = config.part {
+            self.default_part = part;
+        }
+        self.default_year = config.year;
+        self
+    }
+
+    /// Registers the days of an event, failing if the year was already added.
+    pub fn with_year(mut self, year: u16, days: Vec<Box<dyn Day>>) -> anyhow::Result<Self> {
+        if self.years.contains_key(&year) {
+            anyhow::bail!("Year {} registered more than once", year)
+        }
+        self.years.insert(year, Registry::new(days)?);
+        Ok(self)
+    }
+
+    /// Sets the crate `new` generates days of `year` into.
+    pub fn with_scaffold(mut self, year: u16, scaffold: Scaffold) -> Self {
+        self.scaffolds.insert(year, scaffold);
+        self
+    }
+
+    pub fn run(mut self) -> anyhow::Result<Output> {
+        let matches = cli::command().get_matches();
+
+        let config = match matches.get_one::<PathBuf>("config") {
+            Some(path) => Some(Config::load(path)?),
+            None => Config::discover(&std::env::current_dir()?)?,
+        };
+        if let Some(config) = config {
+            self = self.with_config(&config);
+        }
+        // the dashboard owns the terminal, diagnostics would garble it
+        if !matches!(matches.subcommand_name(), Some("tui")) {
+            let days = matches
+                .get_many::<usize>("log-day")
+                .into_iter()
+                .flatten()
+                .copied()
+                .collect::<Vec<_>>();
+            diagnostics::init(matches.get_count("verbose"), &days);
+        }
+
+        if matches.get_flag("raw-input") {
+            self.normalize = Normalize::NONE;
+        }
+        if let Some(format) = matches.get_one::<String>("format") {
+            self.format = format.parse()?;
+        }
+        if let Some(jobs) = matches.get_one::<usize>("jobs") {
+            self.jobs = *jobs;
+        }
+        if let Some(timeout) = matches.get_one::<String>("timeout") {
+            self.timeout = Some(parse_duration(timeout)?);
+        }
+        let year = self.year(matches.get_one::<u16>("year").copied())?;
+        match matches.subcommand() {
+            Some(("all", _)) => self.all(year),
+            Some(("list", _)) => {
+                let summary = self.list(year)?;
+                Ok(Output {
+                    text: match self.format {
+                        Format::Text => summary.stars(),
+                        Format::Json => format::summary_json(&summary),
+                    },
+                    success: summary.success(),
+                })
+            }
+            Some(("examples", examples)) => {
+                let examples = self.examples(year, examples.get_one::<usize>("day").copied())?;
+                Ok(Output {
+                    text: match self.format {
+                        Format::Text => examples.to_string(),
+                        Format::Json => format::examples_json(&examples),
+                    },
+                    success: examples.success(),
+                })
+            }
+            Some(("new", new)) => self.new_day(year, *new.get_one::<usize>("day").unwrap()),
+            Some(("bench", bench)) => self.bench(year, bench),
+            Some(("verify", verify)) => self.verify(year, verify),
+            Some(("watch", watch)) => self.watch(year, watch),
+            Some(("tui", tui)) => self.tui(year, tui),
+            _ => self.run_day(year, &matches),
+        }
+    }
+
+    /// Resolves the requested year, falling back to the configured one and
+    /// then the latest registered one.
+    fn year(&self, requested: Option<u16>) -> anyhow::Result<u16> {
+        match requested.or(self.default_year) {
+            Some(year) => {
+                self.days(year)?;
+                Ok(year)
+            }
+            None => match self.years.keys().next_back() {
+                Some(year) => Ok(*year),
+                None => anyhow::bail!("No years registered"),
+            },
+        }
+    }
+
+    fn days(&self, year: u16) -> anyhow::Result<&Registry> {
+        match self.years.get(&year) {
+            Some(days) => Ok(days),
+            None => anyhow::bail!(
+                "Year {} not configured, available years: {}",
+                year,
+                self.years.keys().join(", ")
+            ),
+        }
+    }
+
+    fn all(&self, year: u16) -> anyhow::Result<Output> {
+        let summary = self.run_all(year)?;
+        Ok(Output {
+            text: match self.format {
+                Format::Text => summary.to_string(),
+                Format::Json => format::summary_json(&summary),
+            },
+            success: summary.success(),
+        })
+    }
+
+    fn run_day(&self, year: u16, matches: &ArgMatches) -> anyhow::Result<Output> {
+        let Some(day) = matches.get_one::<usize>("day") else {
+            return self.all(year);
+        };
+        let part = matches
+            .get_one::<u8>("part")
+            .copied()
+            .unwrap_or(self.default_part);
+        let day_runner = self.days(year)?.get(*day)?;
+        let input = self.input_source(year, *day, matches)?;
+        let (input, read) = timed(|| input.read().map(|i| self.normalize(day_runner.as_ref(), i)));
+        let input = Arc::from(input?);
+        let (parsed, parse) = timed(|| self.parse(day_runner, &input));
+        let (answer, solve) = match parsed {
+            Ok(parsed) => timed(|| self.solve(day_runner, part, &parsed)),
+            Err(e) => (Err(e), Duration::ZERO),
+        };
+
+        if matches.get_flag("time") {
+            eprintln!("read input: {}", Human(read));
+            eprintln!("parse: {}", Human(parse));
+            eprintln!("part {}: {}", part, Human(solve));
+        }
+        match self.format {
+            Format::Text => answer.map(|a| a.to_string().into()),
+            Format::Json => {
+                let timings = Timings { read, parse, solve };
+                let result = PartResult::new(year, *day, part, answer, timings);
+                Ok(Output {
+                    text: format::part_json(&result),
+                    success: matches!(result.status, Status::Ok),
+                })
+            }
+        }
+    }
+
+    fn bench(&self, year: u16, matches: &ArgMatches) -> anyhow::Result<Output> {
+        let Some(day) = matches.get_one::<usize>("day") else {
             unreachable!()
         };
-        let Some(day_runner) = self.days.get(day-1) else {
-            anyhow::bail!("Day {} not configured", day)
+        let day = self.days(year)?.get(*day)?;
+        let input = self.input_source(year, day.day(), matches)?.read()?;
+        let input = self.normalize(day.as_ref(), input);
+        let config = BenchConfig {
+            iterations: *matches.get_one::<usize>("iterations").unwrap(),
+            warmup: *matches.get_one::<usize>("warmup").unwrap(),
+        };
+        let parts = match matches.get_one::<u8>("part") {
+            Some(part) => vec![*part],
+            None => vec![1, 2],
+        };
+
+        let reports = parts
+            .into_iter()
+            .map(|part| bench(year, day.as_ref(), part, &input, &config))
+            .collect::<anyhow::Result<Vec<_>>>()?;
+
+        let mut comparisons = Vec::new();
+        let (compare, save) = (matches.get_flag("compare"), matches.get_flag("save"));
+        if compare || save {
+            let baseline_path = matches.get_one::<PathBuf>("baseline").unwrap();
+            let baseline = Baseline::load(baseline_path)?;
+            if compare {
+                let threshold = *matches.get_one::<f64>("threshold").unwrap();
+                comparisons = baseline.compare(&reports, threshold);
+            }
+            if save {
+                baseline.save(baseline_path, &reports)?;
+            }
+        }
+        Ok(Output {
+            text: match self.format {
+                Format::Text => reports
+                    .iter()
+                    .map(ToString::to_string)
+                    .chain(comparisons.iter().map(ToString::to_string))
+                    .join("\n"),
+                Format::Json => format::bench_json(&reports, &comparisons),
+            },
+            success: !comparisons.iter().any(Comparison::regressed),
+        })
+    }
+
+    /// The input given on the command line, or the conventional one for the day.
+    fn input_source(
+        &self,
+        year: u16,
+        day: usize,
+        matches: &ArgMatches,
+    ) -> anyhow::Result<InputSource> {
+        if let Some(input) = matches.get_one::<String>("input-str") {
+            return Ok(InputSource::Inline(input.clone()));
+        }
+        match matches.get_one::<PathBuf>("input") {
+            Some(path) => Ok(InputSource::from_arg(path)),
+            None => self.inputs.find(year, day).map(InputSource::File),
+        }
+    }
+
+    fn verify(&self, year: u16, matches: &ArgMatches) -> anyhow::Result<Output> {
+        let answers = Answers::load(matches.get_one::<PathBuf>("answers").unwrap())?;
+        let verification = Verification::new(year, self.run_all(year)?, &answers);
+        if let Some(path) = matches.get_one::<PathBuf>("junit") {
+            std::fs::write(path, junit::report(&verification))?;
+        }
+        Ok(Output {
+            text: match self.format {
+                Format::Text => verification.to_string(),
+                Format::Json => format::verification_json(&verification),
+            },
+            success: verification.failures() == 0,
+        })
+    }
+
+    /// Generates a day from the template along with an empty input.
+    fn new_day(&self, year: u16, day: usize) -> anyhow::Result<Output> {
+        if !(1..=25).contains(&day) {
+            anyhow::bail!("Day must be between 1 and 25, got {}", day)
+        }
+        let Some(scaffold) = self.scaffolds.get(&year) else {
+            anyhow::bail!("Year {} has no crate to generate days into", year)
+        };
+        if self.days(year)?.get(day).is_ok() {
+            anyhow::bail!("Day {} already exists", day)
+        }
+
+        let mut created = vec![scaffold.create(day)?];
+        if self.inputs.find(year, day).is_err() {
+            let path = self.inputs.candidates(year, day).swap_remove(0);
+            if let Some(dir) = path.parent() {
+                std::fs::create_dir_all(dir)?;
+            }
+            std::fs::write(&path, "")?;
+            created.push(path);
+        }
+        Ok(created
+            .iter()
+            .map(|path| format!("Created {}", path.display()))
+            .join("\n")
+            .into())
+    }
+
+    fn tui(&self, year: u16, matches: &ArgMatches) -> anyhow::Result<Output> {
+        let path = matches.get_one::<PathBuf>("answers").unwrap();
+        let answers = if path.exists() {
+            Answers::load(path)?
+        } else {
+            Answers::default()
+        };
+        tui::Dashboard::new(self, year, answers)?.show()?;
+        Ok(String::new().into())
+    }
+
+    /// Re-runs a day whenever its input or one of the watched paths changes,
+    /// until interrupted. The code run is the one already compiled, changes
+    /// to the day's source need a rebuild.
+    fn watch(&self, year: u16, matches: &ArgMatches) -> anyhow::Result<Output> {
+        let day = self
+            .days(year)?
+            .get(*matches.get_one::<usize>("day").unwrap())?;
+        let input = match matches.get_one::<PathBuf>("input") {
+            Some(path) => path.clone(),
+            None => self.inputs.find(year, day.day())?,
+        };
+        let parts = match matches.get_one::<u8>("part") {
+            Some(part) => vec![*part],
+            None => vec![1, 2],
         };
-        let mut input_file = File::open(input)?;
-        let mut input = String::new();
-        input_file.read_to_string(&mut input)?;
+        let interval = parse_duration(matches.get_one::<String>("interval").unwrap())?;
 
-        match part {
-            1 => day_runner.part1(&input),
-            2 => day_runner.part2(&input),
-            _ => unreachable!(),
+        let mut paths = vec![input.clone()];
+        paths.extend(
+            matches
+                .get_many::<PathBuf>("path")
+                .into_iter()
+                .flatten()
+                .cloned(),
+        );
+        let mut watcher = watch::Watcher::new(paths);
+        loop {
+            print!("{}", watch::CLEAR);
+            println!("{}", self.watch_run(year, day, &input, &parts));
+            let strays = watchdog::strays();
+            if strays > 0 {
+                println!(
+                    "\n{} timed out parts still running in the background",
+                    strays
+                );
+            }
+            println!(
+                "\nWatching {} for changes, press Ctrl-C to stop",
+                watcher.paths().iter().map(|p| p.display()).join(", ")
+            );
+            watcher.wait(interval);
         }
     }
+
+    fn watch_run(&self, year: u16, day: &Arc<dyn Day>, input: &Path, parts: &[u8]) -> String {
+        let (input, read) =
+            timed(|| read_input(input).map(|i| Arc::from(self.normalize(day.as_ref(), i))));
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => return format!("error: {:#}", e),
+        };
+        let mut summary = Summary::default();
+        for result in self.solve_parts(year, day, &input, read, parts) {
+            summary.push(result);
+        }
+        summary.to_string()
+    }
+
+    /// Parses the input of a day, giving up once the timeout is exceeded.
+    fn parse(&self, day: &Arc<dyn Day>, input: &Arc<str>) -> anyhow::Result<Parsed> {
+        let day = Arc::clone(day);
+        let input = Arc::clone(input);
+        with_timeout(self.timeout, move || {
+            let _span = tracing::info_span!(diagnostics::SPAN, day = day.day()).entered();
+            panic::catch(|| day.parse_input(&input))
+        })
+    }
+
+    /// Runs a part, giving up on it once the timeout is exceeded.
+    fn solve(&self, day: &Arc<dyn Day>, part: u8, parsed: &Parsed) -> anyhow::Result<Answer> {
+        let day = Arc::clone(day);
+        let parsed = Arc::clone(parsed);
+        with_timeout(self.timeout, move || run_part(day.as_ref(), part, &parsed))
+    }
+
+    fn normalize(&self, day: &dyn Day, input: String) -> String {
+        self.normalize.intersect(day.normalize()).apply(input)
+    }
+
+    /// Runs the examples of every day configured for `year`, or only those of
+    /// `day` when given.
+    pub fn examples(&self, year: u16, day: Option<usize>) -> anyhow::Result<Examples> {
+        let days = match day {
+            Some(day) => vec![self.days(year)?.get(day)?],
+            None => self.days(year)?.iter().collect(),
+        };
+        let results = parallel::map_ordered(self.jobs, days, |day| {
+            day.examples()
+                .iter()
+                .map(|example| {
+                    let input = Arc::from(self.normalize(day.as_ref(), example.input.to_owned()));
+                    (
+                        example,
+                        self.solve_parts(year, day, &input, Duration::ZERO, &[1, 2]),
+                    )
+                })
+                .collect::<Vec<_>>()
+        });
+
+        let mut examples = Examples {
+            year,
+            results: Vec::new(),
+        };
+        for day in results {
+            for (i, (example, results)) in day.into_iter().enumerate() {
+                examples.push(i + 1, example, results);
+            }
+        }
+        Ok(examples)
+    }
+
+    /// Runs both parts of every day configured for `year`, looking up each
+    /// input by convention. Days are spread over `jobs` threads.
+    pub fn run_all(&self, year: u16) -> anyhow::Result<Summary> {
+        let days = self.days(year)?.iter().collect();
+        let results =
+            parallel::map_ordered(self.jobs, days, |day| self.run_parts(year, day, &[1, 2]));
+
+        let mut summary = Summary::default();
+        for result in results.into_iter().flatten() {
+            summary.push(result);
+        }
+        Ok(summary)
+    }
+
+    /// Like `run_all`, except days without an input are run on their first
+    /// example, or an empty input, only to tell whether they are implemented.
+    pub fn list(&self, year: u16) -> anyhow::Result<Summary> {
+        let days = self.days(year)?.iter().collect();
+        let results = parallel::map_ordered(self.jobs, days, |day| {
+            let results = self.run_parts(year, day, &[1, 2]);
+            if !results.iter().all(|r| matches!(r.status, Status::NoInput)) {
+                return results;
+            }
+            let input = day.examples().first().map_or("", |example| example.input);
+            let input = Arc::from(self.normalize(day.as_ref(), input.to_owned()));
+            self.solve_parts(year, day, &input, Duration::ZERO, &[1, 2])
+                .into_iter()
+                .map(|result| match result.status {
+                    Status::NotImplemented => result,
+                    _ => PartResult::no_input(year, result.day, result.part),
+                })
+                .collect()
+        });
+
+        let mut summary = Summary::default();
+        for result in results.into_iter().flatten() {
+            summary.push(result);
+        }
+        Ok(summary)
+    }
+
+    /// Runs parts of a day on its conventional input, parsing it only once.
+    fn run_parts(&self, year: u16, day: &Arc<dyn Day>, parts: &[u8]) -> Vec<PartResult> {
+        let Ok(path) = self.inputs.find(year, day.day()) else {
+            return parts
+                .iter()
+                .map(|part| PartResult::no_input(year, day.day(), *part))
+                .collect();
+        };
+        let (input, read) =
+            timed(|| read_input(&path).map(|i| Arc::from(self.normalize(day.as_ref(), i))));
+        let input = match input {
+            Ok(input) => input,
+            Err(e) => {
+                return parts
+                    .iter()
+                    .map(|part| PartResult::unreadable(year, day.day(), *part, &e))
+                    .collect()
+            }
+        };
+        self.solve_parts(year, day, &input, read, parts)
+    }
+
+    /// Runs parts of a day on an input that was already read.
+    fn solve_parts(
+        &self,
+        year: u16,
+        day: &Arc<dyn Day>,
+        input: &Arc<str>,
+        read: Duration,
+        parts: &[u8],
+    ) -> Vec<PartResult> {
+        let (parsed, parse) = timed(|| self.parse(day, input));
+        let parsed = match parsed {
+            Ok(parsed) => parsed,
+            Err(e) => {
+                let timings = Timings {
+                    read,
+                    parse,
+                    solve: Duration::ZERO,
+                };
+                let failed = PartResult::new(year, day.day(), 1, Err(e), timings);
+                return parts
+                    .iter()
+                    .map(|part| PartResult {
+                        part: *part,
+                        ..failed.clone()
+                    })
+                    .collect();
+            }
+        };
+
+        parts
+            .iter()
+            .map(|&part| {
+                let (result, solve) = timed(|| self.solve(day, part, &parsed));
+                PartResult::new(
+                    year,
+                    day.day(),
+                    part,
+                    result,
+                    Timings { read, parse, solve },
+                )
+            })
+            .collect()
+    }
+}
+
+fn run_part(day: &dyn Day, part: u8, parsed: &Parsed) -> anyhow::Result<Answer> {
+    let _span = tracing::info_span!(diagnostics::SPAN, day = day.day(), part).entered();
+    panic::catch(|| day.solve(part, parsed))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+
+    use super::*;
+
+    #[test]
+    fn defaults_to_latest_year() -> anyhow::Result<()> {
+        let runner = Runner::new()
+            .with_year(2023, Vec::new())?
+            .with_year(2022, Vec::new())?;
+        assert_eq!(runner.year(None)?, 2023);
+        assert_eq!(runner.year(Some(2022))?, 2022);
+        assert_eq!(
+            runner.year(Some(2021)).err().unwrap().to_string(),
+            "Year 2021 not configured, available years: 2022, 2023"
+        );
+        assert!(runner.with_year(2022, Vec::new()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn rejects_new_days_out_of_range() -> anyhow::Result<()> {
+        let dir = env::temp_dir().join("aoc-no-scaffold");
+        let runner = Runner::new()
+            .with_year(2022, Vec::new())?
+            .with_scaffold(2022, Scaffold::new(&dir));
+        for day in [0, 26] {
+            assert_eq!(
+                runner.new_day(2022, day).err().unwrap().to_string(),
+                format!("Day must be between 1 and 25, got {}", day)
+            );
+        }
+        assert!(!dir.exists());
+        Ok(())
+    }
+
+    struct Half;
+
+    impl Day for Half {
+        fn part1(&self, input: &str) -> anyhow::Result<Answer> {
+            Ok(input.len().into())
+        }
+
+        fn day(&self) -> usize {
+            1
+        }
+    }
+
+    #[test]
+    fn lists_parts_without_inputs() -> anyhow::Result<()> {
+        let mut runner = Runner::new().with_year(2022, vec![Box::new(Half)])?;
+        runner.inputs = InputLocator::new(vec![env::temp_dir().join("aoc-no-inputs")]);
+        let summary = runner.list(2022)?;
+        assert_eq!(summary.stars(), "Day  1: ?.");
+        assert!(summary.success());
+        Ok(())
+    }
 }
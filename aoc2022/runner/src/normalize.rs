@@ -0,0 +1,76 @@
+use serde::Deserialize;
+
+/// Clean up steps applied to an input before it is handed to a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Normalize {
+    /// Remove a leading UTF-8 byte order mark.
+    pub strip_bom: bool,
+    /// Convert `\r\n` line endings to `\n`.
+    pub crlf: bool,
+    /// Collapse trailing newlines into exactly one.
+    pub trailing_newline: bool,
+}
+
+impl Default for Normalize {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl Normalize {
+    pub const ALL: Self = Self {
+        strip_bom: true,
+        crlf: true,
+        trailing_newline: true,
+    };
+
+    pub const NONE: Self = Self {
+        strip_bom: false,
+        crlf: false,
+        trailing_newline: false,
+    };
+
+    /// Only keeps the steps enabled in both `self` and `other`.
+    pub fn intersect(self, other: Self) -> Self {
+        Self {
+            strip_bom: self.strip_bom && other.strip_bom,
+            crlf: self.crlf && other.crlf,
+            trailing_newline: self.trailing_newline && other.trailing_newline,
+        }
+    }
+
+    pub fn apply(&self, mut input: String) -> String {
+        if self.strip_bom && input.starts_with('\u{feff}') {
+            input.drain(..'\u{feff}'.len_utf8());
+        }
+        if self.crlf && input.contains('\r') {
+            input = input.replace("\r\n", "\n");
+        }
+        if self.trailing_newline && !input.is_empty() {
+            let len = input.trim_end_matches(['\n', '\r']).len();
+            input.truncate(len);
+            input.push('\n');
+        }
+        input
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes() {
+        let input = "\u{feff}1000\r\n2000\r\n\r\n".to_owned();
+        assert_eq!(Normalize::ALL.apply(input.clone()), "1000\n2000\n");
+        assert_eq!(Normalize::NONE.apply(input.clone()), input);
+        assert_eq!(Normalize::ALL.apply("1000".to_owned()), "1000\n");
+    }
+
+    #[test]
+    fn keeps_leading_whitespace() {
+        let input = "    [D]    \n[N] [C]    \n".to_owned();
+        assert_eq!(Normalize::ALL.apply(input.clone()), input);
+    }
+}
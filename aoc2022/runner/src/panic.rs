@@ -0,0 +1,105 @@
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    fmt::Display,
+    panic::{self, AssertUnwindSafe},
+    sync::Once,
+};
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+    static LOCATION: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+static HOOK: Once = Once::new();
+
+/// Error returned when a part panicked.
+#[derive(Debug, Clone)]
+pub struct Panicked {
+    pub message: String,
+    pub location: Option<String>,
+}
+
+impl Display for Panicked {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.location {
+            Some(location) => write!(f, "panicked at {}: {}", location, self.message),
+            None => write!(f, "panicked: {}", self.message),
+        }
+    }
+}
+
+impl std::error::Error for Panicked {}
+
+/// Runs `f`, turning a panic into a [`Panicked`] error.
+pub fn catch<T>(f: impl FnOnce() -> anyhow::Result<T>) -> anyhow::Result<T> {
+    install_hook();
+    CATCHING.with(|c| c.set(true));
+    let res = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|c| c.set(false));
+
+    res.unwrap_or_else(|payload| {
+        Err(Panicked {
+            message: payload_message(payload.as_ref()),
+            location: LOCATION.with(|l| l.borrow_mut().take()),
+        }
+        .into())
+    })
+}
+
+/// Keeps the default hook for panics outside of [`catch`], and only records
+/// where the panic happened for those inside, as they are reported with the
+/// part's result.
+fn install_hook() {
+    HOOK.call_once(|| {
+        let default = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if CATCHING.with(Cell::get) {
+                let location = info.location().map(ToString::to_string);
+                LOCATION.with(|l| *l.borrow_mut() = location);
+            } else {
+                default(info);
+            }
+        }));
+    });
+}
+
+/// Runs `f` before the current hook on panics outside of [`catch`], e.g. to
+/// restore the terminal before the panic is reported.
+pub(crate) fn on_uncaught(f: impl Fn() + Send + Sync + 'static) {
+    install_hook();
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(move |info| {
+        if !CATCHING.with(Cell::get) {
+            f();
+        }
+        hook(info);
+    }));
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(msg) = payload.downcast_ref::<&str>() {
+        (*msg).to_owned()
+    } else if let Some(msg) = payload.downcast_ref::<String>() {
+        msg.clone()
+    } else {
+        "unknown panic payload".to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catches_panics() {
+        let err = catch::<()>(|| panic!("oh no {}", 42)).unwrap_err();
+        let panicked = err.downcast_ref::<Panicked>().unwrap();
+        assert_eq!(panicked.message, "oh no 42");
+        assert!(panicked
+            .location
+            .as_deref()
+            .unwrap()
+            .starts_with("runner/src/panic.rs:"));
+    }
+}
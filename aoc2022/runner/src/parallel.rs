@@ -0,0 +1,57 @@
+use std::{sync::Mutex, thread};
+
+use crate::watchdog::STACK_SIZE;
+
+/// Maps `items` on up to `jobs` threads, returning the results in the order
+/// of the items.
+pub(crate) fn map_ordered<T, R, F>(jobs: usize, items: Vec<T>, f: F) -> Vec<R>
+where
+    T: Send,
+    R: Send,
+    F: Fn(T) -> R + Sync,
+{
+    let jobs = jobs.min(items.len());
+    if jobs <= 1 {
+        return items.into_iter().map(f).collect();
+    }
+
+    let len = items.len();
+    let queue = Mutex::new(items.into_iter().enumerate());
+    let results = Mutex::new((0..len).map(|_| None).collect::<Vec<Option<R>>>());
+    thread::scope(|s| {
+        for _ in 0..jobs {
+            thread::Builder::new()
+                .stack_size(STACK_SIZE)
+                .spawn_scoped(s, || loop {
+                    let Some((i, item)) = queue.lock().unwrap().next() else {
+                        break;
+                    };
+                    let res = f(item);
+                    results.lock().unwrap()[i] = Some(res);
+                })
+                .expect("failed to spawn a worker thread");
+        }
+    });
+
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(|r| r.expect("every item is processed"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_order() {
+        let items = (0..100u64).collect::<Vec<_>>();
+        let res = map_ordered(8, items, |i| {
+            thread::sleep(std::time::Duration::from_micros(100 - i));
+            i * 2
+        });
+        assert_eq!(res, (0..100).map(|i| i * 2).collect::<Vec<_>>());
+    }
+}
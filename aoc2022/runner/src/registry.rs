@@ -0,0 +1,216 @@
+use std::{collections::BTreeMap, sync::Arc};
+
+use itertools::Itertools;
+
+use crate::{answer::Answer, day::NotImplemented, Day};
+
+/// Days keyed by their puzzle number.
+pub struct Registry(BTreeMap<usize, Arc<dyn Day>>);
+
+impl Registry {
+    /// Builds the registry, failing if two days claim the same number.
+    pub fn new(days: Vec<Box<dyn Day>>) -> anyhow::Result<Self> {
+        let mut registry = BTreeMap::new();
+        for day in days {
+            let num = day.day();
+            if registry.insert(num, Arc::from(day)).is_some() {
+                anyhow::bail!("Day {} registered more than once", num)
+            }
+        }
+        Ok(Self(registry))
+    }
+
+    pub fn get(&self, day: usize) -> anyhow::Result<&Arc<dyn Day>> {
+        match self.0.get(&day) {
+            Some(day) => Ok(day),
+            None => anyhow::bail!(
+                "Day {} not configured, available days: {}",
+                day,
+                self.0.keys().join(", ")
+            ),
+        }
+    }
+
+    /// Iterates over the registered days in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = &Arc<dyn Day>> {
+        self.0.values()
+    }
+}
+
+/// A part given as a free function of the input.
+pub type PartFn = fn(&str) -> anyhow::Result<Answer>;
+
+/// A day registered with `#[aoc::day(N)]`, collected at link time.
+pub struct Registration {
+    /// Module the attribute was used in, used to tell crates apart.
+    pub module: &'static str,
+    pub day: usize,
+    pub solution: Registered,
+}
+
+pub enum Registered {
+    Day(fn() -> Box<dyn Day>),
+    Part(u8, PartFn),
+}
+
+inventory::collect!(Registration);
+
+/// Every day registered with `#[aoc::day(N)]` within `krate`, usually
+/// `module_path!()` at the root of the crate.
+pub fn registered(krate: &str) -> anyhow::Result<Vec<Box<dyn Day>>> {
+    collect(inventory::iter::<Registration>, krate)
+}
+
+fn collect<'a>(
+    registrations: impl IntoIterator<Item = &'a Registration>,
+    krate: &str,
+) -> anyhow::Result<Vec<Box<dyn Day>>> {
+    let mut days = Vec::new();
+    let mut parts: BTreeMap<usize, Parts> = BTreeMap::new();
+    for registration in registrations {
+        let module = registration.module;
+        if module != krate && !module.starts_with(&format!("{}::", krate)) {
+            continue;
+        }
+        match registration.solution {
+            Registered::Day(make) => {
+                let day = make();
+                if day.day() != registration.day {
+                    anyhow::bail!(
+                        "Day {} in {} reports being day {}",
+                        registration.day,
+                        module,
+                        day.day()
+                    )
+                }
+                days.push(day);
+            }
+            Registered::Part(part, f) => {
+                let parts = parts.entry(registration.day).or_insert(Parts {
+                    day: registration.day,
+                    part1: None,
+                    part2: None,
+                });
+                let slot = if part == 1 {
+                    &mut parts.part1
+                } else {
+                    &mut parts.part2
+                };
+                if slot.replace(f).is_some() {
+                    anyhow::bail!(
+                        "Day {} part {} registered more than once",
+                        registration.day,
+                        part
+                    )
+                }
+            }
+        }
+    }
+    days.extend(parts.into_values().map(|p| Box::new(p) as Box<dyn Day>));
+    Ok(days)
+}
+
+/// A day made of free functions.
+struct Parts {
+    day: usize,
+    part1: Option<PartFn>,
+    part2: Option<PartFn>,
+}
+
+impl Day for Parts {
+    fn part1(&self, input: &str) -> anyhow::Result<Answer> {
+        match self.part1 {
+            Some(part1) => part1(input),
+            None => Err(NotImplemented.into()),
+        }
+    }
+
+    fn part2(&self, input: &str) -> anyhow::Result<Answer> {
+        match self.part2 {
+            Some(part2) => part2(input),
+            None => Err(NotImplemented.into()),
+        }
+    }
+
+    fn day(&self) -> usize {
+        self.day
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Stub(usize);
+
+    impl Day for Stub {
+        fn day(&self) -> usize {
+            self.0
+        }
+    }
+
+    #[test]
+    fn rejects_duplicates() {
+        let res = Registry::new(vec![Box::new(Stub(1)), Box::new(Stub(1))]);
+        assert_eq!(
+            res.err().unwrap().to_string(),
+            "Day 1 registered more than once"
+        );
+    }
+
+    fn stub() -> Box<dyn Day> {
+        Box::new(Stub(3))
+    }
+
+    fn lines(input: &str) -> anyhow::Result<Answer> {
+        Ok(input.lines().count().into())
+    }
+
+    #[test]
+    fn collects_registrations() -> anyhow::Result<()> {
+        let registrations = [
+            Registration {
+                module: "aoc22::day3",
+                day: 3,
+                solution: Registered::Day(stub),
+            },
+            Registration {
+                module: "aoc22::day4",
+                day: 4,
+                solution: Registered::Part(2, lines),
+            },
+            Registration {
+                module: "aoc221::day1",
+                day: 1,
+                solution: Registered::Day(stub),
+            },
+        ];
+        let registry = Registry::new(collect(&registrations, "aoc22")?)?;
+        assert_eq!(registry.iter().map(|d| d.day()).collect_vec(), [3, 4]);
+        let day4 = registry.get(4)?;
+        assert_eq!(day4.part2("a\nb")?, Answer::Int(2));
+        assert!(day4.part1("a").unwrap_err().is::<NotImplemented>());
+
+        let registrations = [Registration {
+            module: "aoc22",
+            day: 4,
+            solution: Registered::Day(stub),
+        }];
+        assert_eq!(
+            collect(&registrations, "aoc22").err().unwrap().to_string(),
+            "Day 4 in aoc22 reports being day 3"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn lookup_by_day_number() -> anyhow::Result<()> {
+        let registry = Registry::new(vec![Box::new(Stub(9)), Box::new(Stub(2))])?;
+        assert_eq!(registry.get(9)?.day(), 9);
+        assert_eq!(
+            registry.get(6).err().unwrap().to_string(),
+            "Day 6 not configured, available days: 2, 9"
+        );
+        Ok(())
+    }
+}
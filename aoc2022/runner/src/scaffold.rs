@@ -0,0 +1,134 @@
+use std::{
+    fs::{self, OpenOptions},
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+};
+
+/// Placeholder for the day number in the template.
+const DAY: &str = "{{day}}";
+
+/// The crate holding the days of a year, laid out as `day.rs_template` next
+/// to `src/lib.rs` and one `src/dayN.rs` per day.
+#[derive(Debug, Clone)]
+pub struct Scaffold {
+    dir: PathBuf,
+}
+
+impl Scaffold {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// Renders the template into `src/dayN.rs` and registers it in
+    /// `src/lib.rs`, failing if the file already exists.
+    pub fn create(&self, day: usize) -> anyhow::Result<PathBuf> {
+        let template = read(&self.dir.join("day.rs_template"))?;
+        let lib_path = self.dir.join("src").join("lib.rs");
+        let lib = register(&read(&lib_path)?, day)?;
+
+        let path = self.dir.join("src").join(format!("day{}.rs", day));
+        let mut file = match OpenOptions::new().write(true).create_new(true).open(&path) {
+            Ok(file) => file,
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                anyhow::bail!("{} already exists", path.display())
+            }
+            Err(e) => anyhow::bail!("could not create {}: {}", path.display(), e),
+        };
+        file.write_all(render(&template, day).as_bytes())?;
+        fs::write(&lib_path, lib)?;
+        Ok(path)
+    }
+}
+
+fn read(path: &Path) -> anyhow::Result<String> {
+    fs::read_to_string(path)
+        .map_err(|e| anyhow::anyhow!("could not read {}: {}", path.display(), e))
+}
+
+fn render(template: &str, day: usize) -> String {
+    template.replace(DAY, &day.to_string())
+}
+
+/// Adds `mod dayN;` to the modules of `lib`, keeping them sorted. The day
+/// itself is registered by its `#[aoc::day(N)]` attribute.
+fn register(lib: &str, day: usize) -> anyhow::Result<String> {
+    let mut lines: Vec<String> = lib.lines().map(str::to_owned).collect();
+    let module = format!("mod day{};", day);
+    if lines.iter().any(|line| line.trim() == module) {
+        anyhow::bail!("Day {} is already registered", day)
+    }
+
+    insert_sorted(&mut lines, day, module, |line| {
+        line.strip_prefix("mod day")?
+            .strip_suffix(';')?
+            .parse()
+            .ok()
+    })?;
+
+    let mut lib = lines.join("\n");
+    lib.push('\n');
+    Ok(lib)
+}
+
+/// Inserts `entry` among the lines for which `day_of` returns a day, before
+/// the first one of a later day and with the same indentation.
+fn insert_sorted(
+    lines: &mut Vec<String>,
+    day: usize,
+    entry: String,
+    day_of: impl Fn(&str) -> Option<usize>,
+) -> anyhow::Result<()> {
+    let entries: Vec<(usize, usize)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_of(line).map(|d| (i, d)))
+        .collect();
+    let Some(&(last, _)) = entries.last() else {
+        anyhow::bail!("could not find where to register `{}`", entry)
+    };
+    let at = entries
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map(|(i, _)| *i)
+        .unwrap_or(last + 1);
+
+    let reference = &lines[entries[0].0];
+    let indent = &reference[..reference.len() - reference.trim_start().len()];
+    lines.insert(at, format!("{}{}", indent, entry));
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LIB: &str = "use runner::Day;
+
+mod day1;
+mod day3;
+
+pub fn days() -> anyhow::Result<Vec<Box<dyn Day>>> {
+    runner::registered(module_path!())
+}
+";
+
+    #[test]
+    fn registers_in_order() -> anyhow::Result<()> {
+        let lib = register(LIB, 2)?;
+        assert!(lib.contains("mod day1;\nmod day2;\nmod day3;\n"));
+
+        let lib = register(&lib, 10)?;
+        assert!(lib.contains("mod day3;\nmod day10;\n\npub fn days()"));
+
+        assert!(register(&lib, 3).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn renders_day() {
+        assert_eq!(
+            render("pub struct Day{{day}};\n    {{day}}\n", 9),
+            "pub struct Day9;\n    9\n"
+        );
+    }
+}
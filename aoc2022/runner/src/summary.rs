@@ -0,0 +1,255 @@
+use std::fmt::Display;
+
+use crate::{
+    panic::Panicked,
+    table::write_table,
+    timing::{Human, Timings},
+    watchdog::TimedOut,
+    Answer, NotImplemented,
+};
+
+/// Outcome of running a single part of a day.
+#[derive(Debug, Clone)]
+pub enum Status {
+    Ok,
+    Failed(String),
+    Panicked(String),
+    TimedOut(String),
+    NotImplemented,
+    NoInput,
+}
+
+impl Status {
+    /// Whether the part ran into a problem, a missing input is not one.
+    pub fn is_failure(&self) -> bool {
+        matches!(
+            self,
+            Status::Failed(_) | Status::Panicked(_) | Status::TimedOut(_)
+        )
+    }
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Status::Ok => write!(f, "ok"),
+            Status::Failed(_) => write!(f, "error"),
+            Status::Panicked(_) => write!(f, "panic"),
+            Status::TimedOut(_) => write!(f, "timeout"),
+            Status::NotImplemented => write!(f, "not implemented"),
+            Status::NoInput => write!(f, "no input"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub year: u16,
+    pub day: usize,
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub status: Status,
+    pub timings: Option<Timings>,
+}
+
+impl PartResult {
+    pub fn new(
+        year: u16,
+        day: usize,
+        part: u8,
+        result: anyhow::Result<Answer>,
+        timings: Timings,
+    ) -> Self {
+        let (answer, status) = match result {
+            Ok(answer) => (Some(answer), Status::Ok),
+            Err(e) if e.downcast_ref::<TimedOut>().is_some() => {
+                (None, Status::TimedOut(e.to_string()))
+            }
+            Err(e) if e.downcast_ref::<NotImplemented>().is_some() => {
+                (None, Status::NotImplemented)
+            }
+            Err(e) if e.downcast_ref::<Panicked>().is_some() => {
+                (None, Status::Panicked(e.to_string()))
+            }
+            Err(e) => (None, Status::Failed(format!("{:#}", e))),
+        };
+        Self {
+            year,
+            day,
+            part,
+            answer,
+            status,
+            timings: Some(timings),
+        }
+    }
+
+    pub fn no_input(year: u16, day: usize, part: u8) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            status: Status::NoInput,
+            timings: None,
+        }
+    }
+
+    /// The input exists but could not be read.
+    pub fn unreadable(year: u16, day: usize, part: u8, err: &anyhow::Error) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            answer: None,
+            status: Status::Failed(format!("{:#}", err)),
+            timings: None,
+        }
+    }
+
+    fn columns(&self) -> [String; 7] {
+        let answer = match &self.status {
+            Status::Failed(msg) | Status::Panicked(msg) | Status::TimedOut(msg) => msg.clone(),
+            _ => self
+                .answer
+                .as_ref()
+                .map(Answer::summary)
+                .unwrap_or_default(),
+        };
+        [
+            self.day.to_string(),
+            self.part.to_string(),
+            answer,
+            self.status.to_string(),
+            self.timings
+                .map(|t| Human(t.read).to_string())
+                .unwrap_or_else(|| "-".to_owned()),
+            self.timings
+                .map(|t| Human(t.parse).to_string())
+                .unwrap_or_else(|| "-".to_owned()),
+            self.timings
+                .map(|t| Human(t.solve).to_string())
+                .unwrap_or_else(|| "-".to_owned()),
+        ]
+    }
+}
+
+/// Table of results for a multi day run.
+#[derive(Default)]
+pub struct Summary(Vec<PartResult>);
+
+impl Summary {
+    pub fn push(&mut self, result: PartResult) {
+        self.0.push(result);
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &PartResult> {
+        self.0.iter()
+    }
+
+    pub fn success(&self) -> bool {
+        !self.0.iter().any(|r| r.status.is_failure())
+    }
+
+    /// One line per day with a symbol for each part, `*` for an answer, `.`
+    /// for a part not implemented yet, `!` for a failure and `?` for a
+    /// missing input.
+    pub fn stars(&self) -> String {
+        self.0
+            .chunk_by(|a, b| a.day == b.day)
+            .map(|parts| {
+                let stars = parts
+                    .iter()
+                    .map(|p| match p.status {
+                        Status::Ok => '*',
+                        Status::NotImplemented => '.',
+                        Status::NoInput => '?',
+                        _ => '!',
+                    })
+                    .collect::<String>();
+                format!("Day {:>2}: {}", parts[0].day, stars)
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl IntoIterator for Summary {
+    type Item = PartResult;
+    type IntoIter = std::vec::IntoIter<PartResult>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Display for Summary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_table(
+            f,
+            ["Day", "Part", "Answer", "Status", "Read", "Parse", "Solve"],
+            self.0.iter().map(PartResult::columns),
+        )?;
+
+        // multi-line answers do not fit in the table
+        for result in &self.0 {
+            if let Some(answer) = result.answer.as_ref().filter(|a| a.is_multiline()) {
+                write!(
+                    f,
+                    "\n\nDay {} part {}:\n{}",
+                    result.day, result.part, answer
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn aligns_columns() {
+        let mut summary = Summary::default();
+        summary.push(PartResult::new(
+            2022,
+            1,
+            1,
+            Ok(24000u32.into()),
+            Timings {
+                read: Duration::from_micros(40),
+                parse: Duration::from_micros(10),
+                solve: Duration::from_millis(2),
+            },
+        ));
+        summary.push(PartResult::no_input(2022, 12, 2));
+
+        assert_eq!(
+            summary.to_string(),
+            "Day | Part | Answer | Status   | Read    | Parse   | Solve\n\
+             1   | 1    | 24000  | ok       | 40.00µs | 10.00µs | 2.00ms\n\
+             12  | 2    |        | no input | -       | -       | -"
+        );
+    }
+
+    #[test]
+    fn star_chart() {
+        let timings = Timings::default();
+        let mut summary = Summary::default();
+        summary.push(PartResult::new(2022, 1, 1, Ok(1u8.into()), timings));
+        summary.push(PartResult::new(
+            2022,
+            1,
+            2,
+            Err(NotImplemented.into()),
+            timings,
+        ));
+        summary.push(PartResult::no_input(2022, 2, 1));
+        summary.push(PartResult::no_input(2022, 2, 2));
+
+        assert!(summary.success());
+        assert_eq!(summary.stars(), "Day  1: *.\nDay  2: ??");
+    }
+}
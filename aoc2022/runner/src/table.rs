@@ -0,0 +1,33 @@
+use std::fmt::{Formatter, Result};
+
+/// Writes rows as left aligned columns separated by `|`.
+pub(crate) fn write_table<const N: usize>(
+    f: &mut Formatter<'_>,
+    header: [&str; N],
+    rows: impl IntoIterator<Item = [String; N]>,
+) -> Result {
+    let rows: Vec<[String; N]> = std::iter::once(header.map(str::to_owned))
+        .chain(rows)
+        .collect();
+
+    let mut widths = [0; N];
+    for row in &rows {
+        for (width, col) in widths.iter_mut().zip(row) {
+            *width = (*width).max(col.chars().count());
+        }
+    }
+
+    for (i, row) in rows.iter().enumerate() {
+        if i > 0 {
+            writeln!(f)?;
+        }
+        let line = row
+            .iter()
+            .zip(widths)
+            .map(|(col, width)| format!("{:<width$}", col, width = width))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        write!(f, "{}", line.trim_end())?;
+    }
+    Ok(())
+}
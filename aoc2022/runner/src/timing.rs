@@ -0,0 +1,92 @@
+use std::{
+    fmt::Display,
+    time::{Duration, Instant},
+};
+
+/// Runs `f` and returns its output along with the wall-clock time it took.
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let res = f();
+    (res, start.elapsed())
+}
+
+/// Parses durations such as `500ms`, `10s` or `2m`, plain numbers are seconds.
+pub fn parse_duration(s: &str) -> anyhow::Result<Duration> {
+    let s = s.trim();
+    let (num, unit) = match s.find(|c: char| !(c.is_ascii_digit() || c == '.')) {
+        Some(i) => s.split_at(i),
+        None => (s, "s"),
+    };
+    let num: f64 = num
+        .parse()
+        .map_err(|_| anyhow::anyhow!("invalid duration: {}", s))?;
+    let secs = match unit {
+        "ms" => num / 1e3,
+        "s" => num,
+        "m" => num * 60.0,
+        _ => anyhow::bail!("invalid duration unit: {}", unit),
+    };
+    Duration::try_from_secs_f64(secs).map_err(|_| anyhow::anyhow!("duration out of range: {}", s))
+}
+
+/// Formats a duration with the largest unit that keeps it above 1.
+pub struct Human(pub Duration);
+
+impl Display for Human {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let nanos = self.0.as_nanos();
+        if nanos < 1_000 {
+            write!(f, "{}ns", nanos)
+        } else if nanos < 1_000_000 {
+            write!(f, "{:.2}µs", nanos as f64 / 1e3)
+        } else if nanos < 1_000_000_000 {
+            write!(f, "{:.2}ms", nanos as f64 / 1e6)
+        } else {
+            write!(f, "{:.2}s", self.0.as_secs_f64())
+        }
+    }
+}
+
+/// Time spent in each phase of running a part.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Timings {
+    pub read: Duration,
+    pub parse: Duration,
+    pub solve: Duration,
+}
+
+impl Display for Timings {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "read: {}, parse: {}, solve: {}",
+            Human(self.read),
+            Human(self.parse),
+            Human(self.solve)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn human_units() {
+        assert_eq!(Human(Duration::from_nanos(512)).to_string(), "512ns");
+        assert_eq!(Human(Duration::from_nanos(1_500)).to_string(), "1.50µs");
+        assert_eq!(Human(Duration::from_micros(2_345)).to_string(), "2.35ms");
+        assert_eq!(Human(Duration::from_millis(61_000)).to_string(), "61.00s");
+    }
+
+    #[test]
+    fn parses_durations() -> anyhow::Result<()> {
+        assert_eq!(parse_duration("500ms")?, Duration::from_millis(500));
+        assert_eq!(parse_duration("1.5s")?, Duration::from_millis(1500));
+        assert_eq!(parse_duration("2m")?, Duration::from_secs(120));
+        assert_eq!(parse_duration("3")?, Duration::from_secs(3));
+        assert!(parse_duration("3h").is_err());
+        assert!(parse_duration("99999999999999999999999").is_err());
+        Ok(())
+    }
+}
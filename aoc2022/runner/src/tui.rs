@@ -0,0 +1,437 @@
+use std::{env, fmt::Display, io, process::Command, sync::Arc, time::Duration};
+
+use ratatui::{
+    backend::CrosstermBackend,
+    crossterm::{
+        event::{self, Event, KeyCode, KeyEventKind},
+        execute,
+        terminal::{enable_raw_mode, EnterAlternateScreen},
+    },
+    layout::{Constraint, Layout},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Paragraph, Row, Table, TableState},
+    DefaultTerminal, Frame, Terminal,
+};
+
+use crate::{
+    panic,
+    summary::{PartResult, Status},
+    timing::Human,
+    verify::{Answers, Verdict},
+    watchdog, Day, Runner,
+};
+
+/// Inputs parts are run on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Source {
+    Real,
+    Example,
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Source::Real => write!(f, "real input"),
+            Source::Example => write!(f, "examples"),
+        }
+    }
+}
+
+/// A part of a day along with how it did on its last run, if anything could
+/// be run for it.
+struct Entry {
+    day: Arc<dyn Day>,
+    part: u8,
+    last: Option<(PartResult, Verdict)>,
+}
+
+/// Interactive view of every day of a year.
+pub(crate) struct Dashboard<'a> {
+    runner: &'a Runner,
+    year: u16,
+    answers: Answers,
+    source: Source,
+    entries: Vec<Entry>,
+    table: TableState,
+    message: Option<String>,
+}
+
+impl<'a> Dashboard<'a> {
+    pub(crate) fn new(runner: &'a Runner, year: u16, answers: Answers) -> anyhow::Result<Self> {
+        let entries = runner
+            .days(year)?
+            .iter()
+            .flat_map(|day| {
+                [1, 2].map(|part| Entry {
+                    day: Arc::clone(day),
+                    part,
+                    last: None,
+                })
+            })
+            .collect();
+        Ok(Self {
+            runner,
+            year,
+            answers,
+            source: Source::Real,
+            entries,
+            table: TableState::default().with_selected(Some(0)),
+            message: None,
+        })
+    }
+
+    /// Takes over the terminal until the dashboard is closed.
+    pub(crate) fn show(mut self) -> anyhow::Result<()> {
+        panic::on_uncaught(ratatui::restore);
+        let mut terminal = init()?;
+        let res = self.event_loop(&mut terminal);
+        ratatui::restore();
+        res
+    }
+
+    fn event_loop(&mut self, terminal: &mut DefaultTerminal) -> anyhow::Result<()> {
+        self.run_all(terminal)?;
+        loop {
+            terminal.draw(|frame| self.draw(frame))?;
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+            self.message = None;
+            match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => return Ok(()),
+                KeyCode::Down | KeyCode::Char('j') => self.table.select_next(),
+                KeyCode::Up | KeyCode::Char('k') => self.table.select_previous(),
+                KeyCode::Enter | KeyCode::Char('r') => self.run_selected(terminal)?,
+                KeyCode::Char('a') => self.run_all(terminal)?,
+                KeyCode::Char('e') => {
+                    self.source = match self.source {
+                        Source::Real => Source::Example,
+                        Source::Example => Source::Real,
+                    };
+                    self.run_all(terminal)?;
+                }
+                KeyCode::Char('o') => self.open_input(terminal)?,
+                _ => {}
+            }
+        }
+    }
+
+    fn selected(&self) -> Option<usize> {
+        self.table
+            .selected()
+            .map(|i| i.min(self.entries.len().saturating_sub(1)))
+            .filter(|_| !self.entries.is_empty())
+    }
+
+    fn run_selected(&mut self, terminal: &mut DefaultTerminal) -> anyhow::Result<()> {
+        let Some(i) = self.selected() else {
+            return Ok(());
+        };
+        let entry = &self.entries[i];
+        self.message = Some(format!(
+            "Running day {} part {}...",
+            entry.day.day(),
+            entry.part
+        ));
+        terminal.draw(|frame| self.draw(frame))?;
+
+        let entry = &self.entries[i];
+        self.entries[i].last = self.outcomes(&entry.day, &[entry.part]).swap_remove(0);
+        self.message = None;
+        Ok(())
+    }
+
+    fn run_all(&mut self, terminal: &mut DefaultTerminal) -> anyhow::Result<()> {
+        self.message = Some(format!("Running every day on {}...", self.source));
+        terminal.draw(|frame| self.draw(frame))?;
+
+        // both parts of a day share one parse
+        let days = self
+            .entries
+            .chunk_by(|a, b| a.day.day() == b.day.day())
+            .collect();
+        let outcomes = crate::parallel::map_ordered(self.runner.jobs, days, |entries| {
+            let parts = entries.iter().map(|entry| entry.part).collect::<Vec<_>>();
+            self.outcomes(&entries[0].day, &parts)
+        });
+        for (entry, outcome) in self.entries.iter_mut().zip(outcomes.into_iter().flatten()) {
+            entry.last = outcome;
+        }
+        self.message = None;
+        Ok(())
+    }
+
+    /// Runs parts of a day on the current source, parsing each input once
+    /// for all of them. Against examples, the first one failing a part is
+    /// kept, or the last one if they all pass.
+    fn outcomes(&self, day: &Arc<dyn Day>, parts: &[u8]) -> Vec<Option<(PartResult, Verdict)>> {
+        match self.source {
+            Source::Real => self
+                .runner
+                .run_parts(self.year, day, parts)
+                .into_iter()
+                .map(|result| {
+                    let expected = self.answers.get(self.year, day.day(), result.part);
+                    let verdict = Verdict::check(&result, expected);
+                    Some((result, verdict))
+                })
+                .collect(),
+            Source::Example => {
+                let mut last: Vec<Option<(PartResult, Verdict)>> =
+                    parts.iter().map(|_| None).collect();
+                for example in day.examples() {
+                    let pending = parts
+                        .iter()
+                        .zip(&last)
+                        .filter(|(part, last)| {
+                            example.expected(**part).is_some()
+                                && last
+                                    .as_ref()
+                                    .is_none_or(|(_, verdict)| matches!(verdict, Verdict::Pass))
+                        })
+                        .map(|(part, _)| *part)
+                        .collect::<Vec<_>>();
+                    if pending.is_empty() {
+                        continue;
+                    }
+                    let input = self
+                        .runner
+                        .normalize(day.as_ref(), example.input.to_owned());
+                    let results = self.runner.solve_parts(
+                        self.year,
+                        day,
+                        &Arc::from(input),
+                        Duration::ZERO,
+                        &pending,
+                    );
+                    for result in results {
+                        let verdict =
+                            Verdict::check(&result, example.expected(result.part).as_ref());
+                        let i = parts.iter().position(|part| *part == result.part).unwrap();
+                        last[i] = Some((result, verdict));
+                    }
+                }
+                last
+            }
+        }
+    }
+
+    /// Opens the input of the selected day in `$VISUAL` or `$EDITOR`, then
+    /// runs the part again.
+    fn open_input(&mut self, terminal: &mut DefaultTerminal) -> anyhow::Result<()> {
+        let Some(i) = self.selected() else {
+            return Ok(());
+        };
+        if self.source == Source::Example {
+            self.message = Some("Examples are part of the day's source".to_owned());
+            return Ok(());
+        }
+        let day = self.entries[i].day.day();
+        let path = match self.runner.inputs.find(self.year, day) {
+            Ok(path) => path,
+            Err(_) => self.runner.inputs.candidates(self.year, day).swap_remove(0),
+        };
+        let editor = env::var("VISUAL")
+            .or_else(|_| env::var("EDITOR"))
+            .unwrap_or_else(|_| "vi".to_owned());
+
+        ratatui::restore();
+        let status = Command::new(&editor).arg(&path).status();
+        *terminal = init()?;
+        match status {
+            Ok(status) if status.success() => self.run_selected(terminal),
+            Ok(status) => {
+                self.message = Some(format!("{} exited with {}", editor, status));
+                Ok(())
+            }
+            Err(e) => {
+                self.message = Some(format!("could not run {}: {}", editor, e));
+                Ok(())
+            }
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [body, footer] =
+            Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(frame.area());
+
+        let rows = self.entries.iter().map(|entry| {
+            let (label, color) = label(entry.last.as_ref());
+            let (answer, parse, solve) = match &entry.last {
+                Some((result, _)) => (
+                    match &result.status {
+                        Status::Failed(msg) | Status::Panicked(msg) | Status::TimedOut(msg) => {
+                            msg.clone()
+                        }
+                        _ => result
+                            .answer
+                            .as_ref()
+                            .map(|a| a.summary())
+                            .unwrap_or_default(),
+                    },
+                    result
+                        .timings
+                        .map(|t| Human(t.parse).to_string())
+                        .unwrap_or_default(),
+                    result
+                        .timings
+                        .map(|t| Human(t.solve).to_string())
+                        .unwrap_or_default(),
+                ),
+                None => Default::default(),
+            };
+            Row::new([
+                entry.day.day().to_string(),
+                entry.part.to_string(),
+                label.to_owned(),
+                answer,
+                parse,
+                solve,
+            ])
+            .style(Style::new().fg(color))
+        });
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(4),
+                Constraint::Length(5),
+                Constraint::Length(14),
+                Constraint::Fill(1),
+                Constraint::Length(10),
+                Constraint::Length(10),
+            ],
+        )
+        .header(
+            Row::new(["Day", "Part", "Status", "Answer", "Parse", "Solve"])
+                .style(Style::new().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::new().add_modifier(Modifier::REVERSED))
+        .block(
+            Block::bordered().title(format!(" Advent of Code {} - {} ", self.year, self.source)),
+        );
+        frame.render_stateful_widget(table, body, &mut self.table);
+
+        let help = "↑/↓ select  r run  a run all  e examples/real input  o open input  q quit";
+        let mut status = self.message.as_deref().unwrap_or(help).to_owned();
+        // timed out parts cannot be stopped, running them again adds to these
+        let strays = watchdog::strays();
+        if strays > 0 {
+            status = format!("[{} timed out parts still running]  {}", strays, status);
+        }
+        frame.render_widget(Paragraph::new(status), footer);
+    }
+}
+
+/// Like [`ratatui::init`], without its panic hook restoring the terminal on
+/// every panic, including those of parts caught by the runner.
+fn init() -> io::Result<DefaultTerminal> {
+    enable_raw_mode()?;
+    execute!(io::stdout(), EnterAlternateScreen)?;
+    Terminal::new(CrosstermBackend::new(io::stdout()))
+}
+
+/// Status shown for a part and its color.
+fn label(last: Option<&(PartResult, Verdict)>) -> (&'static str, Color) {
+    let Some((result, verdict)) = last else {
+        return ("no example", Color::DarkGray);
+    };
+    match (&result.status, verdict) {
+        (Status::Ok, Verdict::Pass) => ("solved", Color::Green),
+        (Status::Ok, Verdict::Fail { .. }) => ("wrong", Color::Red),
+        (Status::Ok, _) => ("answered", Color::Reset),
+        (Status::Failed(_), _) => ("error", Color::Red),
+        (Status::Panicked(_), _) => ("panicked", Color::Red),
+        (Status::TimedOut(_), _) => ("timeout", Color::Yellow),
+        (Status::NotImplemented, _) => ("unimplemented", Color::DarkGray),
+        (Status::NoInput, _) => ("no input", Color::DarkGray),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+    use crate::{answer::Answer, timing::Timings};
+
+    struct Sum;
+
+    impl Day for Sum {
+        fn part1(&self, input: &str) -> anyhow::Result<Answer> {
+            Ok(input.lines().count().into())
+        }
+
+        fn day(&self) -> usize {
+            1
+        }
+
+        fn examples(&self) -> &'static [crate::Example] {
+            &[crate::Example {
+                input: "a\nb\nc",
+                part1: Some("3"),
+                part2: Some("4"),
+            }]
+        }
+    }
+
+    #[test]
+    fn labels_parts() -> anyhow::Result<()> {
+        let runner = Runner::new().with_year(2022, vec![Box::new(Sum)])?;
+        let mut dashboard = Dashboard::new(&runner, 2022, Answers::default())?;
+        dashboard.source = Source::Example;
+        let labels = dashboard
+            .outcomes(&dashboard.entries[0].day, &[1, 2])
+            .iter()
+            .map(|outcome| label(outcome.as_ref()).0)
+            .collect::<Vec<_>>();
+        assert_eq!(labels, ["solved", "unimplemented"]);
+
+        let timings = Timings::default();
+        let result = PartResult::new(2022, 1, 1, Ok(3.into()), timings);
+        let wrong = Verdict::check(&result, Some(&Answer::Int(4)));
+        assert_eq!(label(Some(&(result, wrong))).0, "wrong");
+        assert_eq!(label(None).0, "no example");
+        Ok(())
+    }
+
+    struct Panics;
+
+    impl Day for Panics {
+        fn part1(&self, _input: &str) -> anyhow::Result<Answer> {
+            panic!("boom")
+        }
+
+        fn day(&self) -> usize {
+            1
+        }
+
+        fn examples(&self) -> &'static [crate::Example] {
+            &[crate::Example {
+                input: "",
+                part1: Some("1"),
+                part2: None,
+            }]
+        }
+    }
+
+    thread_local! {
+        static RESTORED: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[test]
+    fn keeps_terminal_on_panicking_parts() -> anyhow::Result<()> {
+        panic::on_uncaught(|| RESTORED.with(|r| r.set(r.get() + 1)));
+        let runner = Runner::new().with_year(2022, vec![Box::new(Panics)])?;
+        let mut dashboard = Dashboard::new(&runner, 2022, Answers::default())?;
+        dashboard.source = Source::Example;
+        let outcome = dashboard
+            .outcomes(&dashboard.entries[0].day, &[1])
+            .swap_remove(0);
+        assert_eq!(label(outcome.as_ref()).0, "panicked");
+        assert_eq!(RESTORED.with(Cell::get), 0);
+        Ok(())
+    }
+}
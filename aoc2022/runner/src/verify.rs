@@ -0,0 +1,293 @@
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path};
+
+use serde::Deserialize;
+
+use crate::{
+    answer::Answer,
+    summary::{PartResult, Status, Summary},
+    table::write_table,
+};
+
+/// Known correct answers, read from a TOML file laid out as
+///
+/// ```toml
+/// [2022.1]
+/// part1 = 24000
+/// part2 = "45000"
+/// ```
+#[derive(Debug, Default)]
+pub struct Answers(BTreeMap<(u16, usize, u8), Answer>);
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Expected {
+    Int(i64),
+    Str(String),
+}
+
+#[derive(Deserialize)]
+struct DayAnswers {
+    part1: Option<Expected>,
+    part2: Option<Expected>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> anyhow::Result<Self> {
+        let contents = fs::read_to_string(path)
+            .map_err(|e| anyhow::anyhow!("could not read {}: {}", path.display(), e))?;
+        Self::parse(&contents)
+    }
+
+    pub fn parse(contents: &str) -> anyhow::Result<Self> {
+        // TOML keys are always strings
+        let years: BTreeMap<String, BTreeMap<String, DayAnswers>> = toml::from_str(contents)?;
+        let mut answers = BTreeMap::new();
+        for (year, days) in years {
+            let year = year
+                .parse()
+                .map_err(|_| anyhow::anyhow!("invalid year: {}", year))?;
+            for (day, parts) in days {
+                let day = day
+                    .parse()
+                    .map_err(|_| anyhow::anyhow!("invalid day: {}", day))?;
+                for (part, expected) in [(1, parts.part1), (2, parts.part2)] {
+                    let expected = match expected {
+                        Some(Expected::Int(i)) => i.into(),
+                        Some(Expected::Str(s)) => s.into(),
+                        None => continue,
+                    };
+                    answers.insert((year, day, part), expected);
+                }
+            }
+        }
+        Ok(Self(answers))
+    }
+
+    pub fn get(&self, year: u16, day: usize, part: u8) -> Option<&Answer> {
+        self.0.get(&(year, day, part))
+    }
+}
+
+pub enum Verdict {
+    Pass,
+    Fail {
+        expected: Answer,
+        actual: String,
+    },
+    /// The part errored, panicked or timed out, whether or not an answer is
+    /// expected.
+    Error(Status),
+    /// No expected answer is recorded for the part.
+    Missing,
+    NoInput,
+}
+
+impl Verdict {
+    /// Compares the result of a part with its expected answer, if known.
+    pub fn check(result: &PartResult, expected: Option<&Answer>) -> Self {
+        match (&result.status, expected) {
+            (status, _) if status.is_failure() => Verdict::Error(status.clone()),
+            (Status::NoInput, None) => Verdict::NoInput,
+            (_, None) => Verdict::Missing,
+            (Status::Ok, Some(expected)) if result.answer.as_ref() == Some(expected) => {
+                Verdict::Pass
+            }
+            (status, Some(expected)) => Verdict::Fail {
+                expected: expected.clone(),
+                actual: match status {
+                    Status::NotImplemented => "not implemented".to_owned(),
+                    Status::NoInput => "no input".to_owned(),
+                    _ => result
+                        .answer
+                        .as_ref()
+                        .map(ToString::to_string)
+                        .unwrap_or_default(),
+                },
+            },
+        }
+    }
+
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Verdict::Fail { .. } | Verdict::Error(_))
+    }
+
+    /// Writes what went wrong under `heading`, nothing if the part did not
+    /// fail.
+    pub(crate) fn write_details(
+        &self,
+        f: &mut std::fmt::Formatter<'_>,
+        heading: impl Display,
+    ) -> std::fmt::Result {
+        match self {
+            Verdict::Fail { expected, actual } => {
+                write!(f, "\n\n{}:", heading)?;
+                write_diff(f, &expected.to_string(), actual)
+            }
+            Verdict::Error(status) => {
+                write!(f, "\n\n{}: {}", heading, status)?;
+                if let Status::Failed(msg) | Status::Panicked(msg) | Status::TimedOut(msg) = status
+                {
+                    for line in msg.lines() {
+                        write!(f, "\n  {}", line)?;
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Verdict::Pass => write!(f, "PASS"),
+            Verdict::Fail { .. } => write!(f, "FAIL"),
+            Verdict::Error(status) => write!(f, "{}", status.to_string().to_uppercase()),
+            Verdict::Missing => write!(f, "MISSING"),
+            Verdict::NoInput => write!(f, "NO INPUT"),
+        }
+    }
+}
+
+pub struct Verification {
+    pub year: u16,
+    pub results: Vec<(PartResult, Verdict)>,
+}
+
+impl Verification {
+    /// Checks the results of a run against the expected answers.
+    pub fn new(year: u16, summary: Summary, answers: &Answers) -> Self {
+        let results = summary
+            .into_iter()
+            .map(|result| {
+                let expected = answers.get(year, result.day, result.part);
+                let verdict = Verdict::check(&result, expected);
+                (result, verdict)
+            })
+            .collect();
+        Self { year, results }
+    }
+
+    pub fn failures(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|(_, verdict)| verdict.is_failure())
+            .count()
+    }
+}
+
+impl Display for Verification {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write_table(
+            f,
+            ["Day", "Part", "Result"],
+            self.results.iter().map(|(result, verdict)| {
+                [
+                    result.day.to_string(),
+                    result.part.to_string(),
+                    verdict.to_string(),
+                ]
+            }),
+        )?;
+
+        for (result, verdict) in &self.results {
+            verdict.write_details(
+                f,
+                format_args!("{} day {} part {}", self.year, result.day, result.part),
+            )?;
+        }
+        write!(
+            f,
+            "\n\n{} of {} parts failed",
+            self.failures(),
+            self.results.len()
+        )
+    }
+}
+
+/// Writes expected and actual answers, line by line for multi-line answers.
+fn write_diff(f: &mut std::fmt::Formatter<'_>, expected: &str, actual: &str) -> std::fmt::Result {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    if expected.len() <= 1 && actual.len() <= 1 {
+        write!(f, "\n  expected: {}", expected.first().unwrap_or(&""))?;
+        return write!(f, "\n  actual:   {}", actual.first().unwrap_or(&""));
+    }
+    for i in 0..expected.len().max(actual.len()) {
+        match (expected.get(i), actual.get(i)) {
+            (Some(e), Some(a)) if e == a => write!(f, "\n    {}", e)?,
+            (e, a) => {
+                if let Some(e) = e {
+                    write!(f, "\n  - {}", e)?;
+                }
+                if let Some(a) = a {
+                    write!(f, "\n  + {}", a)?;
+                }
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{panic::Panicked, timing::Timings};
+
+    #[test]
+    fn verdicts() -> anyhow::Result<()> {
+        let answers = Answers::parse(
+            r#"
+            [2022.1]
+            part1 = 24000
+            part2 = "45000"
+            "#,
+        )?;
+        let timings = Timings::default();
+        let mut summary = Summary::default();
+        summary.push(PartResult::new(2022, 1, 1, Ok("24000".into()), timings));
+        summary.push(PartResult::new(2022, 1, 2, Ok("41000".into()), timings));
+        summary.push(PartResult::new(2022, 2, 1, Ok("15".into()), timings));
+        summary.push(PartResult::no_input(2022, 3, 1));
+        summary.push(PartResult::no_input(2022, 1, 1));
+        summary.push(PartResult::new(
+            2022,
+            4,
+            2,
+            Err(Panicked {
+                message: "boom".to_owned(),
+                location: Some("src/day4.rs:7:9".to_owned()),
+            }
+            .into()),
+            timings,
+        ));
+
+        let verification = Verification::new(2022, summary, &answers);
+        assert_eq!(verification.failures(), 3);
+        assert_eq!(
+            verification.to_string(),
+            "Day | Part | Result\n\
+             1   | 1    | PASS\n\
+             1   | 2    | FAIL\n\
+             2   | 1    | MISSING\n\
+             3   | 1    | NO INPUT\n\
+             1   | 1    | FAIL\n\
+             4   | 2    | PANIC\n\
+             \n\
+             2022 day 1 part 2:\n  \
+             expected: 45000\n  \
+             actual:   41000\n\
+             \n\
+             2022 day 1 part 1:\n  \
+             expected: 24000\n  \
+             actual:   no input\n\
+             \n\
+             2022 day 4 part 2: panic\n  \
+             panicked at src/day4.rs:7:9: boom\n\
+             \n\
+             3 of 6 parts failed"
+        );
+        Ok(())
+    }
+}
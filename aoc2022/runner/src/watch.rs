@@ -0,0 +1,99 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    path::{Path, PathBuf},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+/// Clears the terminal and moves the cursor to the top left.
+pub(crate) const CLEAR: &str = "\x1b[2J\x1b[H";
+
+/// Polls files for changes, directories are watched recursively.
+pub(crate) struct Watcher {
+    paths: Vec<PathBuf>,
+    last: BTreeMap<PathBuf, SystemTime>,
+}
+
+impl Watcher {
+    pub(crate) fn new(paths: Vec<PathBuf>) -> Self {
+        let last = snapshot(&paths);
+        Self { paths, last }
+    }
+
+    pub(crate) fn paths(&self) -> &[PathBuf] {
+        &self.paths
+    }
+
+    /// Whether a file was modified, added or removed since the last call.
+    pub(crate) fn changed(&mut self) -> bool {
+        let now = snapshot(&self.paths);
+        if now == self.last {
+            return false;
+        }
+        self.last = now;
+        true
+    }
+
+    /// Blocks until a change is seen, checking every `interval`.
+    pub(crate) fn wait(&mut self, interval: Duration) {
+        while !self.changed() {
+            thread::sleep(interval);
+        }
+    }
+}
+
+/// Modification times of every file under `paths`, missing ones are left out.
+fn snapshot(paths: &[PathBuf]) -> BTreeMap<PathBuf, SystemTime> {
+    let mut times = BTreeMap::new();
+    for path in paths {
+        visit(path, &mut times);
+    }
+    times
+}
+
+fn visit(path: &Path, times: &mut BTreeMap<PathBuf, SystemTime>) {
+    let Ok(metadata) = fs::metadata(path) else {
+        return;
+    };
+    if metadata.is_dir() {
+        for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+            visit(&entry.path(), times);
+        }
+    } else if let Ok(modified) = metadata.modified() {
+        times.insert(path.to_owned(), modified);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs::File, process};
+
+    use super::*;
+
+    #[test]
+    fn sees_changes() -> anyhow::Result<()> {
+        let dir = env::temp_dir().join(format!("aoc-watch-{}", process::id()));
+        fs::create_dir_all(&dir)?;
+        let input = dir.join("day01.txt");
+        fs::write(&input, "1")?;
+
+        let mut watcher = Watcher::new(vec![dir.clone()]);
+        assert!(!watcher.changed());
+
+        let later = SystemTime::now() + Duration::from_secs(5);
+        File::options()
+            .write(true)
+            .open(&input)?
+            .set_modified(later)?;
+        assert!(watcher.changed());
+        assert!(!watcher.changed());
+
+        fs::write(dir.join("day02.txt"), "2")?;
+        assert!(watcher.changed());
+
+        fs::remove_dir_all(&dir)?;
+        assert!(watcher.changed());
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use std::{
+    fmt::Display,
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use crate::timing::Human;
+
+/// Stack size of threads running parts, well above the 2 MiB spawned threads
+/// get by default so recursive solutions fit as they do on the main thread.
+pub(crate) const STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// Workers given up on that have not finished yet.
+static STRAYS: AtomicUsize = AtomicUsize::new(0);
+
+/// Error returned when a part exceeds its time budget.
+#[derive(Debug, Clone, Copy)]
+pub struct TimedOut(pub Duration);
+
+impl Display for TimedOut {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "timed out after {}", Human(self.0))
+    }
+}
+
+impl std::error::Error for TimedOut {}
+
+/// Runs `f` on a worker thread, giving up on it after `timeout`.
+///
+/// A thread that timed out cannot be stopped, it is left running in the
+/// background until it finishes or the process exits, see [`strays`].
+pub fn with_timeout<T, F>(timeout: Option<Duration>, f: F) -> anyhow::Result<T>
+where
+    T: Send + 'static,
+    F: FnOnce() -> anyhow::Result<T> + Send + 'static,
+{
+    let Some(timeout) = timeout else {
+        return f();
+    };
+
+    let (tx, rx) = mpsc::channel();
+    let abandoned = Arc::new(Mutex::new(false));
+    let worker = Arc::clone(&abandoned);
+    thread::Builder::new()
+        .stack_size(STACK_SIZE)
+        .spawn(move || {
+            let res = f();
+            if *worker.lock().unwrap() {
+                STRAYS.fetch_sub(1, Ordering::Relaxed);
+            } else {
+                let _ = tx.send(res);
+            }
+        })?;
+    match rx.recv_timeout(timeout) {
+        Ok(res) => res,
+        Err(RecvTimeoutError::Timeout) => {
+            let mut abandoned = abandoned.lock().unwrap();
+            // the worker may have finished right before the lock was taken
+            if let Ok(res) = rx.try_recv() {
+                return res;
+            }
+            *abandoned = true;
+            STRAYS.fetch_add(1, Ordering::Relaxed);
+            Err(TimedOut(timeout).into())
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            anyhow::bail!("worker thread exited without a result")
+        }
+    }
+}
+
+/// Number of workers that timed out and are still running in the background.
+pub(crate) fn strays() -> usize {
+    STRAYS.load(Ordering::Relaxed)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn times_out() {
+        let res = with_timeout(Some(Duration::from_millis(10)), || {
+            thread::sleep(Duration::from_millis(200));
+            Ok(())
+        });
+        let err = res.unwrap_err();
+        assert!(err.downcast_ref::<TimedOut>().is_some());
+        assert_eq!(err.to_string(), "timed out after 10.00ms");
+
+        // the only test leaving a worker behind
+        assert_eq!(strays(), 1);
+        for _ in 0..100 {
+            if strays() == 0 {
+                return;
+            }
+            thread::sleep(Duration::from_millis(50));
+        }
+        panic!("the worker is still counted once finished");
+    }
+
+    #[test]
+    fn fits_deep_recursion() -> anyhow::Result<()> {
+        fn depth(n: usize) -> usize {
+            let frame = std::hint::black_box([0u8; 1024]);
+            if n == 0 {
+                frame.len()
+            } else {
+                depth(n - 1) + 1
+            }
+        }
+        let res = with_timeout(Some(Duration::from_secs(10)), || Ok(depth(8 * 1024)))?;
+        assert_eq!(res, 8 * 1024 + 1024);
+        Ok(())
+    }
+
+    #[test]
+    fn finishes_in_time() -> anyhow::Result<()> {
+        let res = with_timeout(Some(Duration::from_secs(10)), || Ok(42))?;
+        assert_eq!(res, 42);
+        Ok(())
+    }
+}